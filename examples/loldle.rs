@@ -1,42 +1,17 @@
 use std::io::BufRead;
 
-use rowdle::Guessable;
+use rowdle_derive::Guessable;
 extern crate rowdle;
 
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, PartialEq, Clone, Default, Guessable)]
 pub struct Champion {
     pub name: String,
     pub mana: bool,
     pub location: String,
-}
-
-impl Guessable<String> for Champion {
-    fn guess(&self, other: &Self) -> rowdle::Guess<Champion, String> {
-        let mut result = vec![];
-
-        if self.name == other.name {
-            result.push(rowdle::GuessResult::Correct(self.name.clone()));
-        } else {
-            result.push(rowdle::GuessResult::Incorrect(self.name.clone()));
-        }
-
-        if self.mana == other.mana {
-            result.push(rowdle::GuessResult::Correct(self.mana.to_string()));
-        } else {
-            result.push(rowdle::GuessResult::Incorrect(self.mana.to_string()));
-        }
-
-        if self.location == other.location {
-            result.push(rowdle::GuessResult::Correct(self.location.clone()));
-        } else {
-            result.push(rowdle::GuessResult::Incorrect(self.location.clone()));
-        }
-
-        rowdle::Guess {
-            word: self.clone(),
-            guess: result,
-        }
-    }
+    #[guessable(numeric, name = "release", unit = "year")]
+    pub release_year: u16,
+    #[guessable(set)]
+    pub positions: Vec<String>,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -46,6 +21,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         name: "Garen".to_string(),
         mana: false,
         location: "Top".to_string(),
+        release_year: 2010,
+        positions: vec!["Top".to_string()],
     };
 
     let word_list = vec![
@@ -53,26 +30,36 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             name: "Garen".to_string(),
             mana: false,
             location: "Top".to_string(),
+            release_year: 2010,
+            positions: vec!["Top".to_string()],
         },
         Champion {
             name: "Darius".to_string(),
             mana: false,
             location: "Top".to_string(),
+            release_year: 2012,
+            positions: vec!["Top".to_string()],
         },
         Champion {
             name: "Vayne".to_string(),
             mana: true,
             location: "Bot".to_string(),
+            release_year: 2011,
+            positions: vec!["Bot".to_string(), "Top".to_string()],
         },
         Champion {
             name: "Zed".to_string(),
             mana: false,
             location: "Mid".to_string(),
+            release_year: 2012,
+            positions: vec!["Mid".to_string()],
         },
         Champion {
             name: "Jinx".to_string(),
             mana: true,
             location: "Bot".to_string(),
+            release_year: 2013,
+            positions: vec!["Bot".to_string()],
         },
     ];
 
@@ -86,10 +73,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         match guess {
             Some(guess) => match game.guess(guess.clone()) {
                 Ok(res) => {
-                    for r in res.guess {
-                        print!("{:?} ", r);
+                    for (column, r) in game.columns().iter().zip(res.guess) {
+                        print!("{}: ", column.label());
+                        match r {
+                            rowdle::GuessResult::Higher(year) => print!("{} ⬆️ ", year),
+                            rowdle::GuessResult::Lower(year) => print!("{} ⬇️ ", year),
+                            rowdle::GuessResult::Partial(positions) => {
+                                print!("{} (partial) ", positions)
+                            }
+                            r => print!("{:?} ", r),
+                        }
                     }
                     println!();
+                    println!("{} champions remaining", game.remaining_count());
                 }
                 Err(e) => {
                     println!("Error: {:?}", e);
@@ -8,25 +8,62 @@ use ratatui::{
     prelude::*,
     widgets::*,
 };
-use rowdle::{guesser::Guess, Game, GuessResult};
+use rowdle::{
+    guesser::{compare, Guess},
+    Game, GuessResult, Guessable,
+};
 use std::io::{self};
 use std::io::{BufRead, Stdout};
 
 extern crate rowdle;
 
-fn gen_words(n: usize) -> (String, Vec<String>) {
+/// A number guessed digit by digit, every wrong digit points towards the correct digit
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Number(String);
+
+impl Guessable<char> for Number {
+    fn guess(&self, other: &Self) -> Guess<Number, char> {
+        Guess {
+            word: self.clone(),
+            guess: self
+                .0
+                .chars()
+                .zip(other.0.chars())
+                .map(|(digit, correct)| compare(digit, &correct))
+                .collect(),
+        }
+    }
+
+    fn atom_count(&self) -> usize {
+        self.0.chars().count()
+    }
+
+    fn atoms(&self) -> Vec<char> {
+        self.0.chars().collect()
+    }
+}
+
+impl std::fmt::Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn gen_words(n: usize) -> (Number, Vec<Number>) {
     // generate all number sequences of length n
     let start = 10u32.pow(n as u32 - 1);
     let end = 10u32.pow(n as u32) - 1;
 
     let words = (start..=end)
-        .map(|i| i.to_string())
-        .collect::<Vec<String>>();
+        .map(|i| Number(i.to_string()))
+        .collect::<Vec<Number>>();
 
-    let random = (start..=end)
-        .choose(&mut rand::thread_rng())
-        .unwrap()
-        .to_string();
+    let random = Number(
+        (start..=end)
+            .choose(&mut rand::thread_rng())
+            .unwrap()
+            .to_string(),
+    );
 
     (random, words)
 }
@@ -52,8 +89,8 @@ fn shutdown_terminal(
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    println!("Welcome to Wordle!");
-    println!("How long should the word be?");
+    println!("Welcome to Numberdle!");
+    println!("How long should the number be?");
     let n: usize = std::io::stdin().lock().lines().next().unwrap()?.parse()?;
 
     let mut terminal = setup_terminal()?;
@@ -68,14 +105,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             let game_board = game.board(
                 Some(0),
                 Some(Guess {
-                    word: input_buffer.clone(),
+                    word: Number(input_buffer.clone()),
                     guess: input_buffer
                         .chars()
-                        .map(|c| rowdle::GuessResult::Incorrect(c))
-                        .chain(
-                            std::iter::repeat(rowdle::GuessResult::Empty)
-                                .take(game.correct_word().len() - input_buffer.len()),
-                        )
+                        .map(rowdle::GuessResult::Incorrect)
+                        .chain(std::iter::repeat_n(
+                            rowdle::GuessResult::Empty,
+                            game.correct_word().atom_count() - input_buffer.chars().count(),
+                        ))
                         .collect(),
                 }),
             );
@@ -85,22 +122,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             event_handler(&mut game, &mut input_buffer)?;
         } else {
             terminal.draw(|f| {
-                play_again_screen(f, f.size(), game.won(), game.correct_word().clone());
+                play_again_screen(f, f.size(), game.won(), game.correct_word().0.clone());
             })?;
 
             if event::poll(std::time::Duration::from_millis(100))? {
                 if let Event::Key(key) = event::read()? {
-                    match key.code {
-                        KeyCode::Char(c) => {
-                            if c == 'y' {
-                                let (word, word_list) = gen_words(n);
-                                game = rowdle::Game::new(5, word, word_list);
-                                input_buffer.clear();
-                            } else if c == 'n' {
-                                break;
-                            }
+                    if let KeyCode::Char(c) = key.code {
+                        if c == 'y' {
+                            let (word, word_list) = gen_words(n);
+                            game = rowdle::Game::new(5, word, word_list);
+                            input_buffer.clear();
+                        } else if c == 'n' {
+                            break;
                         }
-                        _ => {}
                     }
                 }
             }
@@ -111,16 +145,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn event_handler(game: &mut Game<char, String>, buffer: &mut String) -> io::Result<()> {
+fn event_handler(game: &mut Game<char, Number>, buffer: &mut String) -> io::Result<()> {
     if event::poll(std::time::Duration::from_millis(100))? {
         if let Event::Key(key) = event::read()? {
             match key.code {
-                KeyCode::Char(c) => {
-                    if c.is_numeric() && buffer.len() < game.correct_word().len() {
-                        buffer.push(c);
-                    }
+                KeyCode::Char(c)
+                    if c.is_numeric()
+                        && buffer.chars().count() < game.correct_word().atom_count() =>
+                {
+                    buffer.push(c);
                 }
-                KeyCode::Enter => match game.guess(buffer.clone()) {
+                KeyCode::Enter => match game.guess(Number(buffer.clone())) {
                     Ok(_) => {
                         buffer.clear();
                     }
@@ -132,7 +167,9 @@ fn event_handler(game: &mut Game<char, String>, buffer: &mut String) -> io::Resu
                 KeyCode::Backspace => {
                     buffer.pop();
                 }
-                KeyCode::Esc => game.end_game(),
+                KeyCode::Esc => {
+                    game.end_game();
+                }
                 _ => {}
             }
         }
@@ -141,9 +178,9 @@ fn event_handler(game: &mut Game<char, String>, buffer: &mut String) -> io::Resu
     Ok(())
 }
 
-fn ui(frame: &mut Frame, game: Vec<Guess<String, char>>) {
+fn ui(frame: &mut Frame, game: Vec<Guess<Number, char>>) {
     let main_block = Block::bordered()
-        .title("Wordle TUI")
+        .title("Numberdle TUI")
         .title_alignment(Alignment::Center);
 
     let area = frame.size();
@@ -151,7 +188,7 @@ fn ui(frame: &mut Frame, game: Vec<Guess<String, char>>) {
     frame.render_widget(main_block, area);
 }
 
-fn board(frame: &mut Frame, rect: Rect, rows: Vec<Guess<String, char>>) {
+fn board(frame: &mut Frame, rect: Rect, rows: Vec<Guess<Number, char>>) {
     let mut constraints = vec![Constraint::Fill(1)];
     rows.iter()
         .for_each(|_| constraints.push(Constraint::Length(3)));
@@ -168,7 +205,7 @@ fn board(frame: &mut Frame, rect: Rect, rows: Vec<Guess<String, char>>) {
     }
 }
 
-fn row(frame: &mut Frame, rect: Rect, row: Guess<String, char>) {
+fn row(frame: &mut Frame, rect: Rect, row: Guess<Number, char>) {
     let mut constraints = vec![Constraint::Fill(1)];
     row.guess
         .iter()
@@ -213,6 +250,22 @@ fn cell(frame: &mut Frame, cell: &GuessResult<char>, layout: &Rect) {
                 *layout,
             );
         }
+        GuessResult::Higher(c) => {
+            frame.render_widget(
+                Paragraph::new(format!("{} ↑", c))
+                    .alignment(Alignment::Center)
+                    .block(cell_block.fg(Color::Indexed(220))),
+                *layout,
+            );
+        }
+        GuessResult::Lower(c) => {
+            frame.render_widget(
+                Paragraph::new(format!("{} ↓", c))
+                    .alignment(Alignment::Center)
+                    .block(cell_block.fg(Color::Indexed(220))),
+                *layout,
+            );
+        }
         GuessResult::Empty => {
             frame.render_widget(
                 Paragraph::new("")
@@ -8,7 +8,7 @@ use ratatui::{
     prelude::*,
     widgets::*,
 };
-use rowdle::{guesser::Guess, Game, GuessResult};
+use rowdle::{guesser::Guess, Game, GuessResult, Guessable};
 use std::io::{self};
 use std::io::{BufRead, Stdout};
 
@@ -63,11 +63,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     word: input_buffer.clone(),
                     guess: input_buffer
                         .chars()
-                        .map(|c| rowdle::GuessResult::Incorrect(c))
-                        .chain(
-                            std::iter::repeat(rowdle::GuessResult::Empty)
-                                .take(game.correct_word().len() - input_buffer.len()),
-                        )
+                        .map(rowdle::GuessResult::Incorrect)
+                        .chain(std::iter::repeat_n(
+                            rowdle::GuessResult::Empty,
+                            game.correct_word().atom_count() - input_buffer.chars().count(),
+                        ))
                         .collect(),
                 }),
             );
@@ -82,17 +82,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             if event::poll(std::time::Duration::from_millis(100))? {
                 if let Event::Key(key) = event::read()? {
-                    match key.code {
-                        KeyCode::Char(c) => {
-                            if c == 'y' {
-                                let (word, word_list) = gen_words(n);
-                                game = rowdle::Game::new(5, word, word_list);
-                                input_buffer.clear();
-                            } else if c == 'n' {
-                                break;
-                            }
+                    if let KeyCode::Char(c) = key.code {
+                        if c == 'y' {
+                            let (word, word_list) = gen_words(n);
+                            game = rowdle::Game::new(5, word, word_list);
+                            input_buffer.clear();
+                        } else if c == 'n' {
+                            break;
                         }
-                        _ => {}
                     }
                 }
             }
@@ -107,10 +104,11 @@ fn event_handler(game: &mut Game<char, String>, buffer: &mut String) -> io::Resu
     if event::poll(std::time::Duration::from_millis(100))? {
         if let Event::Key(key) = event::read()? {
             match key.code {
-                KeyCode::Char(c) => {
-                    if c.is_alphabetic() && buffer.len() < game.correct_word().len() {
-                        buffer.push(c);
-                    }
+                KeyCode::Char(c)
+                    if c.is_alphabetic()
+                        && buffer.chars().count() < game.correct_word().atom_count() =>
+                {
+                    buffer.push(c);
                 }
                 KeyCode::Enter => match game.guess(buffer.clone()) {
                     Ok(_) => {
@@ -124,7 +122,9 @@ fn event_handler(game: &mut Game<char, String>, buffer: &mut String) -> io::Resu
                 KeyCode::Backspace => {
                     buffer.pop();
                 }
-                KeyCode::Esc => game.end_game(),
+                KeyCode::Esc => {
+                    game.end_game();
+                }
                 _ => {}
             }
         }
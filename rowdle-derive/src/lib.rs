@@ -0,0 +1,233 @@
+//! Derive macro for the `Guessable` trait of [rowdle](https://crates.io/crates/rowdle)
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, Index, LitStr, Member};
+
+/// Derive `Guessable<String>` for a struct of attributes, like the champions of Loldle
+/// Every field is one atom of the guess, rendered with `ToString`
+///
+/// Each field is compared according to its `#[guessable(...)]` attribute:
+/// * `exact` - `Correct` if the fields are equal, `Incorrect` otherwise. This is the default
+/// * `numeric` - `Correct` if the fields are equal, otherwise `Higher` or `Lower`
+///   pointing towards the correct value
+///   The fields are compared as numbers, but the atoms are their rendered
+///   strings, which do not order as numbers (`"100" < "50"`). Use
+///   `Knowledge::is_consistent_ordered`, which reads `Number` columns as
+///   numbers, rather than comparing the atoms
+/// * `set` - for collections: `Correct` if both hold the same items, `Partial`
+///   if they share some items and `Incorrect` if they share none
+/// * `skip` - the field is not part of the guess
+///
+/// The derived `columns` describes every field that is not skipped. The kind
+/// follows the comparison (`Category`, `Number` or `Set`) and the name defaults
+/// to the field name, or its index for tuple structs. Both the name and a unit
+/// can be set with `#[guessable(name = "...", unit = "...")]`
+///
+/// # Example
+///
+/// ```
+/// use rowdle::{GuessResult, Guessable};
+///
+/// #[derive(Debug, Clone, PartialEq, Default, Guessable)]
+/// struct Champion {
+///     name: String,
+///     #[guessable(numeric, name = "Release", unit = "year")]
+///     release_year: u16,
+///     #[guessable(set)]
+///     positions: Vec<String>,
+///     #[guessable(skip)]
+///     title: String,
+/// }
+///
+/// let garen = Champion {
+///     name: "Garen".to_string(),
+///     release_year: 2010,
+///     positions: vec!["Top".to_string()],
+///     title: "The Might of Demacia".to_string(),
+/// };
+/// let sett = Champion {
+///     name: "Sett".to_string(),
+///     release_year: 2020,
+///     positions: vec!["Top".to_string(), "Support".to_string()],
+///     title: "The Boss".to_string(),
+/// };
+///
+/// assert_eq!(garen.columns()[1].label(), "Release (year)");
+/// assert_eq!(
+///     garen.guess(&sett).guess,
+///     vec![
+///         GuessResult::Incorrect("Garen".to_string()),
+///         GuessResult::Higher("2010".to_string()),
+///         GuessResult::Partial("Top".to_string()),
+///     ]
+/// );
+/// ```
+#[proc_macro_derive(Guessable, attributes(guessable))]
+pub fn derive_guessable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The `Options` struct holds the `#[guessable(...)]` attribute of a field
+struct Options {
+    compare: Compare,
+    name: Option<LitStr>,
+    unit: Option<LitStr>,
+}
+
+impl Options {
+    /// Read the options of a field from its `#[guessable(...)]` attributes
+    fn of(field: &Field) -> syn::Result<Self> {
+        let mut options = Options {
+            compare: Compare::Exact,
+            name: None,
+            unit: None,
+        };
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("guessable"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("exact") {
+                    options.compare = Compare::Exact;
+                } else if meta.path.is_ident("numeric") {
+                    options.compare = Compare::Numeric;
+                } else if meta.path.is_ident("set") {
+                    options.compare = Compare::Set;
+                } else if meta.path.is_ident("skip") {
+                    options.compare = Compare::Skip;
+                } else if meta.path.is_ident("name") {
+                    options.name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("unit") {
+                    options.unit = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(
+                        meta.error("expected `exact`, `numeric`, `set`, `skip`, `name` or `unit`")
+                    );
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(options)
+    }
+
+    /// Generate the expression describing the column of a field
+    fn column(&self, member: &Member) -> Option<TokenStream2> {
+        let kind = match self.compare {
+            Compare::Exact => quote!(Category),
+            Compare::Numeric => quote!(Number),
+            Compare::Set => quote!(Set),
+            Compare::Skip => return None,
+        };
+
+        let name = match (&self.name, member) {
+            (Some(name), _) => name.value(),
+            (None, Member::Named(ident)) => ident.to_string(),
+            (None, Member::Unnamed(index)) => index.index.to_string(),
+        };
+
+        let column =
+            quote!(::rowdle::guesser::Column::new(#name, ::rowdle::guesser::ColumnKind::#kind));
+        Some(match &self.unit {
+            Some(unit) => quote!(#column.with_unit(#unit)),
+            None => column,
+        })
+    }
+}
+
+/// The `Compare` enum represents how a field is compared
+enum Compare {
+    Exact,
+    Numeric,
+    Set,
+    Skip,
+}
+
+impl Compare {
+    /// Generate the expression scoring a field of `self` against the same field of `other`
+    fn result(&self, member: &Member) -> Option<TokenStream2> {
+        let result = quote!(::rowdle::GuessResult);
+
+        match self {
+            Compare::Exact => Some(quote! {
+                if self.#member == other.#member {
+                    #result::Correct(::std::string::ToString::to_string(&self.#member))
+                } else {
+                    #result::Incorrect(::std::string::ToString::to_string(&self.#member))
+                }
+            }),
+            Compare::Numeric => Some(quote! {
+                ::rowdle::guesser::compare(&self.#member, &&other.#member)
+                    .map(::std::string::ToString::to_string)
+            }),
+            Compare::Set => Some(quote! {
+                {
+                    let atom = ::std::iter::IntoIterator::into_iter(&self.#member)
+                        .map(::std::string::ToString::to_string)
+                        .collect::<::std::vec::Vec<_>>()
+                        .join(", ");
+                    ::rowdle::guesser::overlap(atom, &self.#member, &other.#member)
+                }
+            }),
+            Compare::Skip => None,
+        }
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields.named.iter().collect(),
+            Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+            Fields::Unit => vec![],
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`Guessable` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut results = vec![];
+    let mut columns = vec![];
+    for (i, field) in fields.into_iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        };
+        let options = Options::of(field)?;
+        results.extend(options.compare.result(&member));
+        columns.extend(options.column(&member));
+    }
+
+    let name = &input.ident;
+    let count = results.len();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::rowdle::Guessable<::std::string::String> for #name #ty_generics #where_clause {
+            fn guess(&self, other: &Self) -> ::rowdle::Guess<Self, ::std::string::String> {
+                ::rowdle::Guess {
+                    word: ::std::clone::Clone::clone(self),
+                    guess: ::std::vec![#(#results),*],
+                }
+            }
+
+            fn atom_count(&self) -> usize {
+                #count
+            }
+
+            fn columns(&self) -> ::std::vec::Vec<::rowdle::guesser::Column> {
+                ::std::vec![#(#columns),*]
+            }
+        }
+    })
+}
@@ -0,0 +1,375 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use crate::dictionary::Dictionary;
+use crate::error::WordleError;
+use crate::guesser::{Guess, Guessable};
+use crate::pattern::{feedback_key, FeedbackKey, Mark};
+use crate::{board_rows, check_guess, Playable};
+
+/// The `TieBreak` enum decides which bucket an `Absurdle` game keeps when
+/// several buckets of candidates have the same size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TieBreak {
+    /// Keep the bucket whose feedback reveals the least: the fewest correct
+    /// atoms, then the fewest misplaced atoms
+    #[default]
+    LeastRevealing,
+    /// Keep the bucket whose feedback reveals the most
+    MostRevealing,
+    /// Keep the bucket holding the earliest candidate in answer order
+    FirstCandidate,
+}
+
+impl TieBreak {
+    /// The key of a feedback, the bucket with the smallest key is kept
+    /// Remaining ties are broken by the marks from last to first, the order of `Pattern::code`
+    fn key(self, feedback: &FeedbackKey, first: usize) -> (usize, usize, Vec<Mark>) {
+        let mut marks: Vec<Mark> = match feedback {
+            Ok(pattern) => pattern.marks().collect(),
+            Err(marks) => marks.clone(),
+        };
+        marks.reverse();
+
+        let count = |mark| marks.iter().filter(|m| **m == mark).count();
+        let revealed = (count(Mark::Correct), count(Mark::Misplaced));
+
+        match self {
+            TieBreak::LeastRevealing => (revealed.0, revealed.1, marks),
+            TieBreak::MostRevealing => (usize::MAX - revealed.0, usize::MAX - revealed.1, marks),
+            TieBreak::FirstCandidate => (first, 0, vec![]),
+        }
+    }
+}
+
+/// An adversarial game of Wordle that never commits to an answer
+/// After each guess the candidates are split by the feedback they would give,
+/// and the largest bucket is kept. The game is won once a single candidate is
+/// left and it is guessed
+///
+/// # Example
+///
+/// ```
+/// use rowdle::absurdle::Absurdle;
+///
+/// let word_list = vec!["hello".to_string(), "jello".to_string(), "world".to_string()];
+/// let mut game = Absurdle::new(6, word_list);
+///
+/// // hello and jello give the same feedback to `world`, so they are kept
+/// game.guess("world".to_string()).unwrap();
+/// assert_eq!(game.remaining_count(), 2);
+///
+/// game.guess("hello".to_string()).unwrap();
+/// assert_eq!(game.candidates(), ["jello"]);
+/// game.guess("jello".to_string()).unwrap();
+/// assert!(game.won());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Absurdle<
+    T: PartialEq + Clone + Debug,
+    G: Guessable<T> + Default + Debug,
+    D: Dictionary<G> = Vec<G>,
+> {
+    max_tries: u8,
+    word_list: D,
+    answers: Option<Vec<G>>,
+    candidates: Vec<G>,
+    guesses: Vec<Guess<G, T>>,
+    tie_break: TieBreak,
+}
+
+impl<T: PartialEq + Clone + Debug, G: Guessable<T> + Default + Debug, D: Dictionary<G>>
+    Absurdle<T, G, D>
+{
+    /// Create a new adversarial game
+    /// # Arguments
+    /// * `max_tries` - The maximum number of tries allowed
+    /// * `word_list` - A dictionary of words that can be guessed, also used as the list of possible answers,
+    ///   sorted so that the game does not depend on the order of the dictionary
+    pub fn new(max_tries: u8, word_list: D) -> Self
+    where
+        G: Ord,
+    {
+        let mut candidates: Vec<G> = word_list.words().cloned().collect();
+        candidates.sort();
+
+        Self {
+            max_tries,
+            candidates,
+            word_list,
+            answers: None,
+            guesses: vec![],
+            tie_break: TieBreak::default(),
+        }
+    }
+
+    /// Set the list of words that can be the correct word
+    /// The word list then only decides which guesses are allowed
+    /// Answers are always allowed as guesses
+    pub fn with_answers(mut self, answers: Vec<G>) -> Self {
+        self.candidates = answers.clone();
+        self.answers = Some(answers);
+        self
+    }
+
+    /// Set how buckets of the same size are picked
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Get how buckets of the same size are picked
+    pub fn tie_break(&self) -> TieBreak {
+        self.tie_break
+    }
+
+    /// Make a guess
+    /// The candidates are narrowed to the largest bucket of the guess, and the
+    /// feedback of that bucket is returned
+    /// # Arguments
+    /// * `word` - The word to guess
+    /// # Returns
+    /// The feedback of the kept bucket, or `NoAnswers` if the game was created without answers
+    pub fn guess(&mut self, word: G) -> Result<Guess<G, T>, WordleError<G, T>> {
+        if self.candidates.is_empty() {
+            return Err(WordleError::NoAnswers);
+        }
+
+        if self.won() {
+            return Err(WordleError::GameWon);
+        }
+        if self.game_over() {
+            return Err(WordleError::MaxTriesExceeded);
+        }
+
+        let word = check_guess(
+            word,
+            self.word_len(),
+            &self.word_list,
+            self.answers.as_deref(),
+            |w| self.is_word_guessed(w),
+        )?;
+
+        // bucket the candidates by feedback, remembering the first candidate of each bucket
+        let mut buckets: HashMap<FeedbackKey, (usize, Vec<usize>)> = HashMap::new();
+        for (i, candidate) in self.candidates.iter().enumerate() {
+            buckets
+                .entry(feedback_key(&word, candidate))
+                .or_insert((i, vec![]))
+                .1
+                .push(i);
+        }
+
+        let tie_break = self.tie_break;
+        let (_, (_, kept)) = buckets
+            .into_iter()
+            .min_by_key(|(feedback, (first, bucket))| {
+                (usize::MAX - bucket.len(), tie_break.key(feedback, *first))
+            })
+            .ok_or(WordleError::NoAnswers)?;
+
+        let res = word.guess(&self.candidates[kept[0]]);
+        let mut candidates = std::mem::take(&mut self.candidates);
+        self.candidates = kept
+            .into_iter()
+            .map(|i| std::mem::take(&mut candidates[i]))
+            .collect();
+
+        self.guesses.push(res);
+        Ok(self.guesses.last().unwrap().clone())
+    }
+
+    /// Get the number of atoms in a word
+    fn word_len(&self) -> usize {
+        self.candidates.first().map_or(0, |c| c.atom_count())
+    }
+
+    /// Get the words still consistent with every guess
+    pub fn candidates(&self) -> &[G] {
+        &self.candidates
+    }
+
+    /// Get the number of words still consistent with every guess
+    pub fn remaining_count(&self) -> usize {
+        self.candidates.len()
+    }
+
+    /// Get the guesses made so far
+    pub fn guesses(&self) -> &[Guess<G, T>] {
+        &self.guesses
+    }
+
+    /// Check if a word has been guessed
+    pub fn is_word_guessed(&self, word: &G) -> bool {
+        self.guesses.iter().any(|g| g.word == *word)
+    }
+
+    /// Check if the game is won
+    /// A game is won once only one candidate is left and it has been guessed
+    pub fn won(&self) -> bool {
+        self.candidates.len() == 1 && self.is_word_guessed(&self.candidates[0])
+    }
+
+    /// Check if the game is lost
+    /// A game is lost if the maximum number of tries has been exceeded
+    pub fn lost(&self) -> bool {
+        self.guesses.len() >= self.max_tries as usize && !self.won()
+    }
+
+    /// Check if the game is over
+    /// A game is over if the game is won or lost
+    pub fn game_over(&self) -> bool {
+        self.won() || self.lost()
+    }
+
+    /// get a 2d vector of the board
+    /// # Arguments
+    /// * `pad` - The number of empty guesses to pad the board with
+    /// * `buffer` - A buffer guess to add to the board
+    /// # Returns
+    /// A 2d vector of the board
+    pub fn board(&self, pad: Option<u32>, buffer: Option<Guess<G, T>>) -> Vec<Guess<G, T>> {
+        board_rows(&self.guesses, self.word_len(), self.max_tries, pad, buffer)
+    }
+}
+
+impl<T: PartialEq + Clone + Debug, G: Guessable<T> + Default + Debug, D: Dictionary<G>>
+    Playable<T, G> for Absurdle<T, G, D>
+{
+    fn guess(&mut self, word: G) -> Result<Guess<G, T>, WordleError<G, T>> {
+        Absurdle::guess(self, word)
+    }
+
+    fn won(&self) -> bool {
+        Absurdle::won(self)
+    }
+
+    fn lost(&self) -> bool {
+        Absurdle::lost(self)
+    }
+
+    fn board(&self, pad: Option<u32>, buffer: Option<Guess<G, T>>) -> Vec<Guess<G, T>> {
+        Absurdle::board(self, pad, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::guesser::GuessResult;
+    use crate::tests::words;
+    use crate::Game;
+    use std::collections::HashSet;
+    use std::error::Error;
+
+    #[test]
+    fn test_absurdle() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["hello", "jello", "cello", "world", "wordl", "mains"]);
+        let mut game = Absurdle::new(6, word_list);
+
+        // mains misses every other word, so they all stay candidates
+        let res = game.guess("mains".to_string())?;
+        assert!(res
+            .guess
+            .iter()
+            .all(|r| matches!(r, GuessResult::Incorrect(_))));
+        assert_eq!(game.remaining_count(), 5);
+
+        // hello, jello and cello share a bucket, larger than world and wordl
+        game.guess("world".to_string())?;
+        assert_eq!(game.candidates(), ["cello", "hello", "jello"]);
+
+        assert_eq!(
+            game.guess("hella".to_string()),
+            Err(WordleError::InvalidWord("hella".to_string()))
+        );
+        assert_eq!(
+            game.guess("world".to_string()),
+            Err(WordleError::WordAlreadyGuessed("world".to_string()))
+        );
+
+        game.guess("hello".to_string())?;
+        assert_eq!(game.candidates(), ["cello", "jello"]);
+
+        // a tie between cello and jello: the least revealing bucket is kept
+        game.guess("jello".to_string())?;
+        assert_eq!(game.candidates(), ["cello"]);
+        assert!(!game.won());
+
+        game.guess("cello".to_string())?;
+        assert!(game.won());
+        assert!(game.game_over());
+        assert_eq!(game.guess("hello".to_string()), Err(WordleError::GameWon));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tie_break() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["hello", "world"]);
+
+        let mut game = Absurdle::new(6, word_list.clone());
+        game.guess("hello".to_string())?;
+        assert_eq!(game.candidates(), ["world"]);
+
+        let mut game = Absurdle::new(6, word_list.clone()).with_tie_break(TieBreak::MostRevealing);
+        game.guess("hello".to_string())?;
+        assert!(game.won());
+
+        let mut game = Absurdle::new(6, word_list.clone()).with_tie_break(TieBreak::FirstCandidate);
+        game.guess("world".to_string())?;
+        assert_eq!(game.candidates(), ["hello"]);
+
+        // the candidates do not depend on the iteration order of the word list
+        for _ in 0..10 {
+            let word_list: HashSet<String> = word_list.iter().cloned().collect();
+            let mut game = Absurdle::new(6, word_list).with_tie_break(TieBreak::FirstCandidate);
+            game.guess("world".to_string())?;
+            assert_eq!(game.candidates(), ["hello"]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_absurdle_long_words() -> Result<(), Box<dyn Error>> {
+        // too long to be packed into a pattern
+        let word_list = vec!["a".repeat(22), "b".repeat(22), "c".repeat(22)];
+        let mut game = Absurdle::new(6, word_list.clone());
+
+        game.guess(word_list[0].clone())?;
+        assert_eq!(game.candidates(), &word_list[1..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_absurdle_no_answers() {
+        let mut game = Absurdle::new(6, words(&["hello", ""])).with_answers(vec![]);
+        assert_eq!(game.guess("".to_string()), Err(WordleError::NoAnswers));
+
+        let mut game = Absurdle::<char, String>::new(6, vec![]);
+        assert_eq!(game.guess("hello".to_string()), Err(WordleError::NoAnswers));
+    }
+
+    #[test]
+    fn test_playable() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["hello", "world"]);
+        let mut modes: Vec<Box<dyn Playable<char, String>>> = vec![
+            Box::new(Game::new(6, "world".to_string(), word_list.clone())),
+            Box::new(Absurdle::new(6, word_list)),
+        ];
+
+        for mode in &mut modes {
+            mode.guess("hello".to_string())?;
+            assert!(!mode.game_over());
+            assert_eq!(mode.board(Some(6), None).len(), 6);
+            mode.guess("world".to_string())?;
+            assert!(mode.won());
+        }
+
+        Ok(())
+    }
+}
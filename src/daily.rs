@@ -0,0 +1,363 @@
+use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::dictionary::Dictionary;
+use crate::guesser::Guessable;
+use crate::rng::SplitMix64;
+use crate::Game;
+
+/// The `Date` struct represents a day in the proleptic Gregorian calendar
+/// Dates can only be built through `Date::new`, so they always exist
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawDate"))]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+/// The fields of a serialized date, checked by `Date::new` when deserializing
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawDate> for Date {
+    type Error = String;
+
+    fn try_from(raw: RawDate) -> Result<Self, Self::Error> {
+        Date::new(raw.year, raw.month, raw.day)
+            .ok_or_else(|| format!("{}-{}-{} is not a valid date", raw.year, raw.month, raw.day))
+    }
+}
+
+impl Date {
+    /// The earliest date that can be represented
+    pub const MIN: Date = Date {
+        year: i32::MIN,
+        month: 1,
+        day: 1,
+    };
+
+    /// The latest date that can be represented
+    pub const MAX: Date = Date {
+        year: i32::MAX,
+        month: 12,
+        day: 31,
+    };
+
+    /// Create a date
+    /// Returns `None` if the month or day does not exist
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// Get today's date in UTC
+    pub fn today() -> Self {
+        let seconds = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(elapsed) => elapsed.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64) - 1,
+        };
+        Self::from_days(seconds.div_euclid(86400))
+    }
+
+    /// Create a date from the number of days since 1970-01-01
+    /// Days outside the range of `Date` saturate to `Date::MIN` or `Date::MAX`
+    pub fn from_days(days: i64) -> Self {
+        if days <= Self::MIN.days() {
+            return Self::MIN;
+        }
+        if days >= Self::MAX.days() {
+            return Self::MAX;
+        }
+
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    /// Get the year
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Get the month, from 1 to 12
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Get the day of the month, from 1
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Get the number of days since 1970-01-01
+    pub fn days(&self) -> i64 {
+        // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let mp = if month > 2 { month - 3 } else { month + 9 };
+        let doy = (153 * mp + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+        era * 146097 + doe - 719468
+    }
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The `Daily` struct picks one answer per day from an answer list
+/// The answers are shuffled with a seeded SplitMix64 generator, so every
+/// platform picks the same answer for the same day without a server
+/// Once every answer has been used the list is shuffled again for the next cycle
+///
+/// # Example
+///
+/// ```
+/// use rowdle::daily::{Daily, Date};
+///
+/// let answers = vec!["hello".to_string(), "world".to_string(), "rowdl".to_string()];
+/// let epoch = Date::new(2024, 1, 1).unwrap();
+/// let daily = Daily::new(answers.clone(), epoch, 42);
+///
+/// let date = Date::new(2024, 1, 3).unwrap();
+/// assert_eq!(daily.puzzle_number(date), Some(2));
+/// assert_eq!(daily.date(2), Some(date));
+///
+/// let allowed = vec!["hjcwa".to_string(), "zzzzz".to_string()];
+/// let game = daily.game(date, 6, allowed).unwrap();
+/// assert_eq!(Some(game.correct_word()), daily.answer_for(date));
+/// assert!(game.answers().eq(answers.iter()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Daily<G> {
+    answers: Vec<G>,
+    epoch: Date,
+    seed: u64,
+}
+
+impl<G: Clone> Daily<G> {
+    /// Create a daily schedule
+    /// # Arguments
+    /// * `answers` - The words that can be the correct word
+    /// * `epoch` - The date of puzzle number 0
+    /// * `seed` - The seed of the shuffle
+    pub fn new(answers: Vec<G>, epoch: Date, seed: u64) -> Self {
+        Self {
+            answers,
+            epoch,
+            seed,
+        }
+    }
+
+    /// Get the answers the schedule picks from
+    pub fn answers(&self) -> &[G] {
+        &self.answers
+    }
+
+    /// Get the date of puzzle number 0
+    pub fn epoch(&self) -> Date {
+        self.epoch
+    }
+
+    /// Get the puzzle number of a date
+    /// Returns `None` for dates before the epoch
+    pub fn puzzle_number(&self, date: Date) -> Option<u64> {
+        u64::try_from(date.days() - self.epoch.days()).ok()
+    }
+
+    /// Get the puzzle number of today in UTC
+    pub fn today(&self) -> Option<u64> {
+        self.puzzle_number(Date::today())
+    }
+
+    /// Get the date of a puzzle number
+    /// Returns `None` if the date is after `Date::MAX`
+    pub fn date(&self, number: u64) -> Option<Date> {
+        let days = self.epoch.days().checked_add(i64::try_from(number).ok()?)?;
+        (days <= Date::MAX.days()).then(|| Date::from_days(days))
+    }
+
+    /// Get the answer of a puzzle number
+    /// Returns `None` if there are no answers
+    pub fn answer(&self, number: u64) -> Option<&G> {
+        let len = self.answers.len() as u64;
+        if len == 0 {
+            return None;
+        }
+
+        let order = self.order(number / len);
+        Some(&self.answers[order[(number % len) as usize]])
+    }
+
+    /// Get the answer of a date
+    /// Returns `None` for dates before the epoch or if there are no answers
+    pub fn answer_for(&self, date: Date) -> Option<&G> {
+        self.answer(self.puzzle_number(date)?)
+    }
+
+    /// Create the game of a date
+    /// The schedule's answers become the game's answers
+    /// # Arguments
+    /// * `date` - The date to play
+    /// * `max_tries` - The maximum number of tries allowed
+    /// * `word_list` - A dictionary of words that can be guessed
+    pub fn game<T: PartialEq + Clone + Debug, D: Dictionary<G>>(
+        &self,
+        date: Date,
+        max_tries: u8,
+        word_list: D,
+    ) -> Option<Game<T, G, D>>
+    where
+        G: Guessable<T> + Default + Debug,
+    {
+        let answer = self.answer_for(date)?.clone();
+        Some(Game::new(max_tries, answer, word_list).with_answers(self.answers.clone()))
+    }
+
+    /// Shuffle the answer indices for a cycle through the answer list
+    fn order(&self, cycle: u64) -> Vec<usize> {
+        let mut rng = SplitMix64::new(self.seed ^ cycle.wrapping_mul(SplitMix64::GAMMA));
+        let mut order: Vec<usize> = (0..self.answers.len()).collect();
+        rng.shuffle(&mut order);
+
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date() {
+        assert_eq!(Date::new(1970, 1, 1).unwrap().days(), 0);
+        assert_eq!(Date::new(2021, 6, 19).unwrap().days(), 18797);
+        assert_eq!(Date::new(1969, 12, 31).unwrap().days(), -1);
+        assert_eq!(Date::from_days(18797), Date::new(2021, 6, 19).unwrap());
+        assert_eq!(Date::from_days(-1), Date::new(1969, 12, 31).unwrap());
+
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(1900, 2, 29).is_none());
+        assert!(Date::new(2024, 13, 1).is_none());
+        assert!(Date::new(2024, 4, 31).is_none());
+
+        for days in -800_000..800_000 {
+            let date = Date::from_days(days);
+            assert_eq!(date.days(), days);
+            assert!(Date::new(date.year(), date.month(), date.day()).is_some());
+        }
+
+        assert_eq!(Date::new(2024, 3, 7).unwrap().to_string(), "2024-03-07");
+
+        assert_eq!(Date::from_days(Date::MAX.days()), Date::MAX);
+        assert_eq!(Date::from_days(Date::MIN.days()), Date::MIN);
+        assert_eq!(Date::from_days(i64::MAX), Date::MAX);
+        assert_eq!(Date::from_days(i64::MIN), Date::MIN);
+        assert_eq!(Date::from_days(Date::MAX.days() - 1).day(), 30);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_date_serde() -> Result<(), serde_json::Error> {
+        let date = Date::new(2024, 2, 29).unwrap();
+        let json = serde_json::to_string(&date)?;
+        assert_eq!(serde_json::from_str::<Date>(&json)?, date);
+
+        let invalid = r#"{"year":2023,"month":2,"day":29}"#;
+        assert!(serde_json::from_str::<Date>(invalid).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_daily() {
+        let answers: Vec<String> = ["hello", "world", "rowdl", "wordl", "jello"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let epoch = Date::new(2021, 6, 19).unwrap();
+        let daily = Daily::new(answers.clone(), epoch, 7);
+
+        assert_eq!(daily.puzzle_number(epoch), Some(0));
+        assert_eq!(daily.puzzle_number(Date::new(2021, 6, 18).unwrap()), None);
+        assert_eq!(
+            daily.puzzle_number(Date::new(2022, 6, 19).unwrap()),
+            Some(365)
+        );
+        assert_eq!(daily.date(365), Date::new(2022, 6, 19));
+        assert_eq!(daily.date(u64::MAX), None);
+        assert_eq!(daily.date(i64::MAX as u64), None);
+
+        // every answer is used once per cycle
+        for cycle in 0..3 {
+            let mut used: Vec<&String> = (0..5)
+                .map(|n| daily.answer(cycle * 5 + n).unwrap())
+                .collect();
+            used.sort();
+            let mut expected: Vec<&String> = answers.iter().collect();
+            expected.sort();
+            assert_eq!(used, expected);
+        }
+
+        // the same seed always gives the same schedule
+        let again = Daily::new(answers.clone(), epoch, 7);
+        for n in 0..20 {
+            assert_eq!(daily.answer(n), again.answer(n));
+        }
+        // going back to an earlier cycle gives the same answers
+        for n in (0..20).rev() {
+            assert_eq!(daily.answer(n), again.clone().answer(n));
+        }
+        assert_eq!(daily, again);
+
+        assert_eq!(Daily::<String>::new(vec![], epoch, 7).answer(0), None);
+    }
+
+    #[test]
+    fn test_daily_stable() {
+        // pinned so that a change in the shuffle is noticed
+        let answers: Vec<u32> = (0..100).collect();
+        let daily = Daily::new(answers, Date::new(2024, 1, 1).unwrap(), 2024);
+        let picks: Vec<u32> = (0..5).map(|n| *daily.answer(n).unwrap()).collect();
+        assert_eq!(picks, vec![73, 51, 95, 82, 23]);
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fs::File;
+use std::hash::{BuildHasher, Hash};
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+/// The `Dictionary` trait is used to check which words can be guessed
+/// It is implemented for `Vec` (linear scan), `HashSet`, `BTreeSet` and
+/// [`SortedDictionary`], and can be implemented for tries, FSTs or any other
+/// word store
+pub trait Dictionary<G> {
+    /// Check if a word is in the dictionary
+    fn contains_word(&self, word: &G) -> bool;
+
+    /// The number of words in the dictionary
+    fn len(&self) -> usize;
+
+    /// Check if the dictionary has no words
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the words of the dictionary
+    fn words(&self) -> Box<dyn Iterator<Item = &G> + '_>;
+}
+
+impl<G: PartialEq> Dictionary<G> for Vec<G> {
+    fn contains_word(&self, word: &G) -> bool {
+        self.as_slice().contains(word)
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = &G> + '_> {
+        Box::new(self.as_slice().iter())
+    }
+}
+
+impl<G: Hash + Eq, S: BuildHasher> Dictionary<G> for HashSet<G, S> {
+    fn contains_word(&self, word: &G) -> bool {
+        HashSet::contains(self, word)
+    }
+
+    fn len(&self) -> usize {
+        HashSet::len(self)
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = &G> + '_> {
+        Box::new(HashSet::iter(self))
+    }
+}
+
+impl<G: Ord> Dictionary<G> for BTreeSet<G> {
+    fn contains_word(&self, word: &G) -> bool {
+        BTreeSet::contains(self, word)
+    }
+
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = &G> + '_> {
+        Box::new(BTreeSet::iter(self))
+    }
+}
+
+/// The `SortedDictionary` struct is a sorted, deduplicated list of words
+/// Lookups use binary search, and it takes less memory than a `HashSet`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SortedDictionary<G: Ord> {
+    words: Vec<G>,
+}
+
+impl<G: Ord> SortedDictionary<G> {
+    /// Create a dictionary, sorting and deduplicating the words
+    pub fn new(mut words: Vec<G>) -> Self {
+        words.sort();
+        words.dedup();
+        Self { words }
+    }
+
+    /// Get the words, in order
+    pub fn as_slice(&self) -> &[G] {
+        &self.words
+    }
+}
+
+impl<G: Ord> FromIterator<G> for SortedDictionary<G> {
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<G: Ord> Dictionary<G> for SortedDictionary<G> {
+    fn contains_word(&self, word: &G) -> bool {
+        self.words.binary_search(word).is_ok()
+    }
+
+    fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = &G> + '_> {
+        Box::new(self.words.iter())
+    }
+}
+
+/// Read a newline-delimited word list into any dictionary
+/// Surrounding whitespace is trimmed and blank lines are skipped
+///
+/// # Example
+///
+/// ```
+/// use rowdle::dictionary::{self, Dictionary};
+/// use std::collections::HashSet;
+///
+/// let words: HashSet<String> = dictionary::from_reader("hello\nworld\n\n".as_bytes()).unwrap();
+/// assert_eq!(words.len(), 2);
+/// assert!(words.contains_word(&"world".to_string()));
+/// ```
+pub fn from_reader<D: FromIterator<String>>(reader: impl BufRead) -> io::Result<D> {
+    lines(reader)
+        .filter_map(|(_, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(Ok(line.trim().to_string())),
+            Err(e) => Some(Err(e)),
+        })
+        .collect()
+}
+
+/// Split a reader into lines numbered from 1, without their line endings
+/// Lines that are not valid UTF-8 give an `InvalidData` error
+pub(crate) fn lines(reader: impl BufRead) -> impl Iterator<Item = (usize, io::Result<String>)> {
+    reader.split(b'\n').enumerate().map(|(i, bytes)| {
+        let line = bytes.and_then(|mut bytes| {
+            if bytes.last() == Some(&b'\r') {
+                bytes.pop();
+            }
+            String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        });
+        (i + 1, line)
+    })
+}
+
+/// Read a newline-delimited word list file into any dictionary
+/// Surrounding whitespace is trimmed and blank lines are skipped
+pub fn from_file<D: FromIterator<String>>(path: impl AsRef<Path>) -> io::Result<D> {
+    from_reader(BufReader::new(File::open(path)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check<D: Dictionary<String>>(dictionary: &D) {
+        assert_eq!(dictionary.len(), 3);
+        assert!(!dictionary.is_empty());
+        assert!(dictionary.contains_word(&"hello".to_string()));
+        assert!(!dictionary.contains_word(&"hella".to_string()));
+
+        let mut words: Vec<&String> = dictionary.words().collect();
+        words.sort();
+        assert_eq!(words, vec!["hello", "rowdl", "world"]);
+    }
+
+    #[test]
+    fn test_dictionaries() -> io::Result<()> {
+        let text = "hello\n  world \n\nrowdl\r\nhello\n";
+
+        let words: Vec<String> = from_reader(text.as_bytes())?;
+        assert_eq!(words.len(), 4);
+
+        check(&from_reader::<HashSet<String>>(text.as_bytes())?);
+        check(&from_reader::<BTreeSet<String>>(text.as_bytes())?);
+
+        let sorted: SortedDictionary<String> = from_reader(text.as_bytes())?;
+        check(&sorted);
+        assert_eq!(sorted.as_slice(), ["hello", "rowdl", "world"]);
+
+        let words = vec![
+            "hello".to_string(),
+            "world".to_string(),
+            "rowdl".to_string(),
+        ];
+        check(&words);
+
+        Ok(())
+    }
+}
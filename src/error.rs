@@ -3,15 +3,49 @@ use std::fmt::Debug;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
-pub enum WordleError<T: PartialEq + Debug> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WordleError<T: PartialEq + Debug, A: PartialEq + Debug> {
     #[error("Max tries exceeded")]
     MaxTriesExceeded,
+    #[error("The game has been abandoned")]
+    GameAbandoned,
+    #[error("The game has already been won")]
+    GameWon,
     #[error("The word `{0}` is not present in the word list")]
     InvalidWord(T),
     #[error("The word `{0}` is not the same length as the word to guess")]
     WordLengthMismatch(T),
     #[error("The word `{0}` has already been guessed")]
     WordAlreadyGuessed(T),
+    #[error("The words `{0}` and `{1}` share atoms")]
+    SharedAtoms(T, T),
+    /// `position` is 0-based, the message counts positions from 1
+    #[error("Hard mode: position {} must be `{atom:?}`", .position + 1)]
+    HardModeCorrect { atom: A, position: usize },
+    #[error("Hard mode: the guess must contain `{0:?}`")]
+    HardModeMisplaced(A),
+    #[error("The game has no answers")]
+    NoAnswers,
     #[error("unknown data store error")]
     Unknown,
 }
+
+/// The `WordListError` enum represents the ways reading a word list can fail
+/// It is non-exhaustive because some errors depend on crate features
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum WordListError {
+    #[error("Failed to read the word list: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Line {line} is not valid UTF-8")]
+    InvalidUtf8 { line: usize },
+    #[error("Line {line} holds more than one word")]
+    MultipleWords { line: usize },
+    #[error("Line {line} has no column {column}")]
+    MissingColumn { line: usize, column: usize },
+    #[error("Line {line} has an unterminated quote")]
+    UnterminatedQuote { line: usize },
+    #[cfg(feature = "json")]
+    #[error("The word list is not a JSON array of strings: {0}")]
+    Json(#[from] serde_json::Error),
+}
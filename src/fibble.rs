@@ -0,0 +1,364 @@
+use std::fmt::Debug;
+
+use crate::dictionary::Dictionary;
+use crate::error::WordleError;
+use crate::guesser::{Guess, GuessResult, Guessable};
+use crate::pattern::Mark;
+use crate::rng::{clock_seed, SplitMix64};
+use crate::{board_rows, check_guess, Playable};
+
+/// A game of Wordle where every feedback row lies
+/// In each row `k` atoms, picked by a seeded generator, are given a false
+/// `Correct`, `Misplaced` or `Incorrect` mark. The row of the correct word
+/// never lies. The lies are recorded and revealed once the game is over
+///
+/// # Example
+///
+/// ```
+/// use rowdle::fibble::Fibble;
+/// use rowdle::Guessable;
+///
+/// let word_list = vec!["hello".to_string(), "world".to_string(), "jello".to_string()];
+/// let mut game = Fibble::new(6, "hello".to_string(), word_list).with_seed(7);
+///
+/// let shown = game.guess("world".to_string()).unwrap();
+/// let truth = "world".to_string().guess(&"hello".to_string());
+/// let lies = shown.guess.iter().zip(&truth.guess).filter(|(a, b)| a != b).count();
+/// assert_eq!(lies, 1);
+/// assert!(game.lies().is_none());
+///
+/// game.guess("hello".to_string()).unwrap();
+/// assert!(game.won());
+/// assert_eq!(game.lies().unwrap()[0].len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fibble<
+    T: PartialEq + Clone + Debug,
+    G: Guessable<T> + Default + Debug,
+    D: Dictionary<G> = Vec<G>,
+> {
+    max_tries: u8,
+    correct_word: G,
+    word_list: D,
+    answers: Option<Vec<G>>,
+    guesses: Vec<Guess<G, T>>,
+    truths: Vec<Guess<G, T>>,
+    lies: Vec<Vec<usize>>,
+    lies_per_row: usize,
+    seed: u64,
+}
+
+impl<T: PartialEq + Clone + Debug, G: Guessable<T> + Default + Debug, D: Dictionary<G>>
+    Fibble<T, G, D>
+{
+    /// Create a new game with one lie per row, seeded from the clock
+    /// # Arguments
+    /// * `max_tries` - The maximum number of tries allowed
+    /// * `correct_word` - The correct word to guess
+    /// * `word_list` - A dictionary of words that can be guessed, also used as the list of possible answers
+    pub fn new(max_tries: u8, correct_word: G, word_list: D) -> Self {
+        Self {
+            max_tries,
+            correct_word,
+            word_list,
+            answers: None,
+            guesses: vec![],
+            truths: vec![],
+            lies: vec![],
+            lies_per_row: 1,
+            seed: clock_seed(),
+        }
+    }
+
+    /// Set the list of words that can be the correct word
+    /// Answers are always allowed as guesses
+    pub fn with_answers(mut self, answers: Vec<G>) -> Self {
+        self.answers = Some(answers);
+        self
+    }
+
+    /// Get the words that can be the correct word
+    pub fn answers(&self) -> Box<dyn Iterator<Item = &G> + '_> {
+        match &self.answers {
+            Some(answers) => Box::new(answers.iter()),
+            None => self.word_list.words(),
+        }
+    }
+
+    /// Set the number of lies in each row
+    /// Rows with fewer atoms than that lie about every atom
+    pub fn with_lies(mut self, lies_per_row: usize) -> Self {
+        self.lies_per_row = lies_per_row;
+        self
+    }
+
+    /// Set the seed deciding which atoms lie and what they say
+    /// The same seed always tells the same lies
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Get the number of lies in each row
+    pub fn lies_per_row(&self) -> usize {
+        self.lies_per_row
+    }
+
+    /// Make a guess
+    /// # Arguments
+    /// * `word` - The word to guess
+    /// # Returns
+    /// The feedback shown to the player, lies included
+    pub fn guess(&mut self, word: G) -> Result<Guess<G, T>, WordleError<G, T>> {
+        if self.won() {
+            return Err(WordleError::GameWon);
+        }
+        if self.game_over() {
+            return Err(WordleError::MaxTriesExceeded);
+        }
+
+        let word = check_guess(
+            word,
+            self.correct_word.atom_count(),
+            &self.word_list,
+            self.answers.as_deref(),
+            |w| self.is_word_guessed(w),
+        )?;
+
+        let truth = word.guess(&self.correct_word);
+        let mut shown = truth.clone();
+        let mut lies = vec![];
+
+        if word != self.correct_word {
+            let row = self.guesses.len() as u64;
+            let mut rng = SplitMix64::new(self.seed ^ row.wrapping_mul(SplitMix64::GAMMA));
+
+            // only atoms with a real mark can lie
+            let mut positions: Vec<usize> = (0..shown.guess.len())
+                .filter(|i| Self::lie_marks(&shown.guess[*i]).is_some())
+                .collect();
+
+            for _ in 0..self.lies_per_row.min(positions.len()) {
+                let i = positions.swap_remove(rng.below(positions.len() as u64) as usize);
+                let result = &mut shown.guess[i];
+                if let (Some(marks), Some(atom)) = (Self::lie_marks(result), result.atom()) {
+                    let mark = marks[rng.below(marks.len() as u64) as usize];
+                    *result = mark.with_atom(atom.clone());
+                    lies.push(i);
+                }
+            }
+            lies.sort_unstable();
+        }
+
+        self.truths.push(truth);
+        self.lies.push(lies);
+        self.guesses.push(shown);
+        Ok(self.guesses.last().unwrap().clone())
+    }
+
+    /// Get the marks a result can lie with
+    /// Returns `None` for results that never lie
+    fn lie_marks(result: &GuessResult<T>) -> Option<[Mark; 2]> {
+        match result {
+            GuessResult::Correct(_) => Some([Mark::Misplaced, Mark::Incorrect]),
+            GuessResult::Misplaced(_) => Some([Mark::Correct, Mark::Incorrect]),
+            GuessResult::Incorrect(_) => Some([Mark::Correct, Mark::Misplaced]),
+            _ => None,
+        }
+    }
+
+    /// Get the feedback shown to the player, lies included
+    pub fn guesses(&self) -> &[Guess<G, T>] {
+        &self.guesses
+    }
+
+    /// Get the true feedback of every guess
+    /// Returns `None` until the game is over
+    pub fn truths(&self) -> Option<&[Guess<G, T>]> {
+        self.game_over().then_some(self.truths.as_slice())
+    }
+
+    /// Get the positions that lied in every row
+    /// Returns `None` until the game is over
+    pub fn lies(&self) -> Option<&[Vec<usize>]> {
+        self.game_over().then_some(self.lies.as_slice())
+    }
+
+    /// Check if a word could still be the correct word
+    /// A word is a candidate if its true feedback differs from the shown
+    /// feedback in exactly as many atoms as each row lied about
+    /// Guessed words are never candidates, the row of the correct word would have won
+    pub fn is_candidate(&self, word: &G) -> bool {
+        self.guesses.iter().all(|shown| {
+            if shown.word == *word {
+                return false;
+            }
+
+            let truth = shown.word.guess(word);
+            let liars = shown
+                .guess
+                .iter()
+                .filter(|r| Self::lie_marks(r).is_some())
+                .count();
+            let differences = truth
+                .guess
+                .iter()
+                .zip(&shown.guess)
+                .filter(|(a, b)| a != b)
+                .count();
+
+            truth.guess.len() == shown.guess.len() && differences == self.lies_per_row.min(liars)
+        })
+    }
+
+    /// Get the answers that could still be the correct word
+    pub fn remaining_candidates(&self) -> Vec<&G> {
+        self.answers().filter(|w| self.is_candidate(w)).collect()
+    }
+
+    /// Get the number of answers that could still be the correct word
+    pub fn remaining_count(&self) -> usize {
+        self.answers().filter(|w| self.is_candidate(w)).count()
+    }
+
+    /// Check if a word has been guessed
+    pub fn is_word_guessed(&self, word: &G) -> bool {
+        self.guesses.iter().any(|g| g.word == *word)
+    }
+
+    /// Check if the game is won
+    /// A game is won if the correct word has been guessed
+    pub fn won(&self) -> bool {
+        self.is_word_guessed(&self.correct_word)
+    }
+
+    /// Check if the game is lost
+    /// A game is lost if the maximum number of tries has been exceeded
+    pub fn lost(&self) -> bool {
+        self.guesses.len() >= self.max_tries as usize && !self.won()
+    }
+
+    /// Check if the game is over
+    /// A game is over if the game is won or lost
+    pub fn game_over(&self) -> bool {
+        self.won() || self.lost()
+    }
+
+    /// Get the correct word
+    pub fn correct_word(&self) -> &G {
+        &self.correct_word
+    }
+
+    /// get a 2d vector of the board, lies included
+    /// # Arguments
+    /// * `pad` - The number of empty guesses to pad the board with
+    /// * `buffer` - A buffer guess to add to the board
+    /// # Returns
+    /// A 2d vector of the board
+    pub fn board(&self, pad: Option<u32>, buffer: Option<Guess<G, T>>) -> Vec<Guess<G, T>> {
+        board_rows(
+            &self.guesses,
+            self.correct_word.atom_count(),
+            self.max_tries,
+            pad,
+            buffer,
+        )
+    }
+}
+
+impl<T: PartialEq + Clone + Debug, G: Guessable<T> + Default + Debug, D: Dictionary<G>>
+    Playable<T, G> for Fibble<T, G, D>
+{
+    fn guess(&mut self, word: G) -> Result<Guess<G, T>, WordleError<G, T>> {
+        Fibble::guess(self, word)
+    }
+
+    fn won(&self) -> bool {
+        Fibble::won(self)
+    }
+
+    fn lost(&self) -> bool {
+        Fibble::lost(self)
+    }
+
+    fn board(&self, pad: Option<u32>, buffer: Option<Guess<G, T>>) -> Vec<Guess<G, T>> {
+        Fibble::board(self, pad, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::words;
+    use std::error::Error;
+
+    #[test]
+    fn test_fibble() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["hello", "world", "jello", "cello", "hills", "wordl"]);
+
+        for lies_per_row in 1..=3 {
+            for seed in 0..20 {
+                let mut game = Fibble::new(6, "hello".to_string(), word_list.clone())
+                    .with_lies(lies_per_row)
+                    .with_seed(seed);
+
+                for word in ["world", "hills", "jello"] {
+                    let shown = game.guess(word.to_string())?;
+                    let truth = word.to_string().guess(&"hello".to_string());
+                    let lies = shown
+                        .guess
+                        .iter()
+                        .zip(&truth.guess)
+                        .filter(|(a, b)| a != b)
+                        .count();
+                    assert_eq!(lies, lies_per_row);
+
+                    // the correct word is always consistent with the lies
+                    assert!(game.is_candidate(&"hello".to_string()));
+                    assert!(!game.is_candidate(&word.to_string()));
+                }
+                assert!(game.truths().is_none());
+
+                let shown = game.guess("hello".to_string())?;
+                assert_eq!(shown, "hello".to_string().guess(&"hello".to_string()));
+                assert!(game.won());
+                assert_eq!(game.guess("world".to_string()), Err(WordleError::GameWon));
+
+                let truths = game.truths().unwrap();
+                let lies = game.lies().unwrap();
+                assert_eq!(truths.len(), 4);
+                for ((truth, shown), lies) in truths.iter().zip(game.guesses()).zip(lies) {
+                    let differ: Vec<usize> = (0..5)
+                        .filter(|i| truth.guess[*i] != shown.guess[*i])
+                        .collect();
+                    assert_eq!(&differ, lies);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fibble_seeded() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["hello", "world", "jello"]);
+        let play = |seed| -> Result<Vec<Guess<String, char>>, WordleError<String, char>> {
+            let mut game = Fibble::new(6, "hello".to_string(), word_list.clone()).with_seed(seed);
+            game.guess("world".to_string())?;
+            game.guess("jello".to_string())?;
+            Ok(game.guesses().to_vec())
+        };
+
+        // the same seed always tells the same lies
+        assert_eq!(play(3)?, play(3)?);
+        assert!((0..10).any(|seed| play(seed) != play(3)));
+
+        let mut game = Fibble::new(1, "hello".to_string(), word_list);
+        game.guess("world".to_string())?;
+        assert!(game.lost());
+        assert_eq!(game.lies().map(|l| l.len()), Some(1));
+
+        Ok(())
+    }
+}
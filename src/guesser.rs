@@ -1,69 +1,382 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::pattern::{Mark, Pattern};
+
 /// The `GuessResult` enum represents the result of a guess
 /// It is a generic enum that can be used to represent the result of each atom in a guess
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GuessResult<T: PartialEq> {
     Correct(T),
     Incorrect(T),
     Misplaced(T),
     Empty,
     Custom(T),
+    /// The correct atom is higher than this atom
+    Higher(T),
+    /// The correct atom is lower than this atom
+    Lower(T),
+    /// The atom shares some, but not all, of its items with the correct atom
+    Partial(T),
+}
+
+impl<T: PartialEq> GuessResult<T> {
+    /// Get a reference to the atom of the result
+    /// Returns `None` for `Empty` results
+    pub fn atom(&self) -> Option<&T> {
+        match self {
+            GuessResult::Correct(a)
+            | GuessResult::Incorrect(a)
+            | GuessResult::Misplaced(a)
+            | GuessResult::Custom(a)
+            | GuessResult::Higher(a)
+            | GuessResult::Lower(a)
+            | GuessResult::Partial(a) => Some(a),
+            GuessResult::Empty => None,
+        }
+    }
+
+    /// Take the atom out of the result
+    /// Returns `None` for `Empty` results
+    pub fn into_atom(self) -> Option<T> {
+        match self {
+            GuessResult::Correct(a)
+            | GuessResult::Incorrect(a)
+            | GuessResult::Misplaced(a)
+            | GuessResult::Custom(a)
+            | GuessResult::Higher(a)
+            | GuessResult::Lower(a)
+            | GuessResult::Partial(a) => Some(a),
+            GuessResult::Empty => None,
+        }
+    }
+
+    /// Turn the atom of the result into another atom, keeping the kind of result
+    pub fn map<U: PartialEq>(self, f: impl FnOnce(T) -> U) -> GuessResult<U> {
+        match self {
+            GuessResult::Correct(a) => GuessResult::Correct(f(a)),
+            GuessResult::Incorrect(a) => GuessResult::Incorrect(f(a)),
+            GuessResult::Misplaced(a) => GuessResult::Misplaced(f(a)),
+            GuessResult::Empty => GuessResult::Empty,
+            GuessResult::Custom(a) => GuessResult::Custom(f(a)),
+            GuessResult::Higher(a) => GuessResult::Higher(f(a)),
+            GuessResult::Lower(a) => GuessResult::Lower(f(a)),
+            GuessResult::Partial(a) => GuessResult::Partial(f(a)),
+        }
+    }
 }
 
 /// The `Guess` struct represents a guess
 /// It is a generic struct that can be used to represent a guess
 #[derive(Debug, Clone, PartialEq, Default, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Guess<T: PartialEq + Clone, G: PartialEq + Clone> {
     pub word: T,
     pub guess: Vec<GuessResult<G>>,
 }
 
+/// The `ColumnKind` enum represents what the atoms of a column are
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColumnKind {
+    /// A letter of a word
+    Letter,
+    /// A value compared for equality, like a region or a class
+    Category,
+    /// A value compared by size, like a release year or a price
+    Number,
+    /// A group of values compared by overlap, like roles or genres
+    Set,
+}
+
+/// The `Column` struct describes one atom position of a guess, so that
+/// front ends can label the results of struct-based games
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Column {
+    pub name: String,
+    pub kind: ColumnKind,
+    pub unit: Option<String>,
+}
+
+impl Column {
+    /// Create a column without a unit
+    /// # Arguments
+    /// * `name` - The name shown to players
+    /// * `kind` - What the atoms of the column are
+    pub fn new(name: impl Into<String>, kind: ColumnKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            unit: None,
+        }
+    }
+
+    /// Set the unit of the column, like `year` or `cm`
+    pub fn with_unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Get the label of the column: its name, followed by its unit in parentheses if it has one
+    pub fn label(&self) -> String {
+        match &self.unit {
+            Some(unit) => format!("{} ({})", self.name, unit),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Create one letter column per position, named by their 1-based position
+    pub fn letters(count: usize) -> Vec<Self> {
+        (1..=count)
+            .map(|i| Self::new(i.to_string(), ColumnKind::Letter))
+            .collect()
+    }
+}
+
 /// The `Guessable` trait is used to implement the guess method
 /// It is a generic trait that can be used to implement the guess method for a type
 /// Any type that implements the `Guessable` trait can be used as a guess
 pub trait Guessable<T: PartialEq + Clone>: PartialEq + Clone {
     fn guess(&self, other: &Self) -> Guess<Self, T>;
+
+    /// The number of atoms a guess of this word is scored on
+    /// Words can only be compared when they have the same atom count
+    /// The default implementation scores the word against itself
+    fn atom_count(&self) -> usize {
+        self.guess(self).guess.len()
+    }
+
+    /// The atoms of the word, in order
+    /// The default implementation scores the word against itself
+    fn atoms(&self) -> Vec<T> {
+        self.guess(self)
+            .guess
+            .into_iter()
+            .filter_map(GuessResult::into_atom)
+            .collect()
+    }
+
+    /// A description of each atom position, in order
+    /// The default implementation returns an empty list, meaning the columns are not described
+    fn columns(&self) -> Vec<Column> {
+        vec![]
+    }
+
+    /// The compact feedback pattern of guessing this word against another
+    /// The default implementation packs the results of `guess`
+    /// Returns `None` if the guess has more than `Pattern::MAX_LEN` results
+    fn pattern(&self, other: &Self) -> Option<Pattern> {
+        Pattern::from_results(&self.guess(other).guess)
+    }
 }
 
-/// Implement the `Guessable` trait for the `String` type
-impl Guessable<char> for String {
-    fn guess(&self, other: &Self) -> Guess<String, char> {
-        // check if the guess is correct
-        if self == other {
-            return Guess {
-                word: self.clone(),
-                guess: other.chars().map(|c| GuessResult::Correct(c)).collect(),
-            };
+/// Score a sequence of atoms against the correct sequence
+/// Atoms in the right position are `Correct`, atoms present elsewhere in the
+/// correct sequence are `Misplaced` (each correct atom is only used once) and
+/// everything else is `Incorrect`
+/// # Arguments
+/// * `guess` - The guessed atoms
+/// * `correct` - The atoms of the correct word
+pub fn score<T: PartialEq + Clone>(guess: &[T], correct: &[T]) -> Vec<GuessResult<T>> {
+    marks(guess, correct)
+        .into_iter()
+        .zip(guess)
+        .map(|(mark, atom)| mark.with_atom(atom.clone()))
+        .collect()
+}
+
+/// Score a sequence of atoms against the correct sequence as a compact pattern
+/// Uses the same rules as [`score`] without copying any atoms
+/// Returns `None` if there are more than `Pattern::MAX_LEN` atoms
+pub fn score_pattern<T: PartialEq>(guess: &[T], correct: &[T]) -> Option<Pattern> {
+    Pattern::from_marks(marks(guess, correct))
+}
+
+/// Compare an atom with the correct atom
+/// Equal atoms are `Correct`, otherwise the result points towards the correct atom
+/// Useful for numeric attributes such as a release year or a price
+///
+/// # Example
+///
+/// ```
+/// use rowdle::guesser::{compare, GuessResult};
+///
+/// assert_eq!(compare(1998, &2004), GuessResult::Higher(1998));
+/// assert_eq!(compare(2010, &2004), GuessResult::Lower(2010));
+/// assert_eq!(compare(2004, &2004), GuessResult::Correct(2004));
+/// ```
+pub fn compare<T: PartialOrd>(atom: T, correct: &T) -> GuessResult<T> {
+    if atom == *correct {
+        GuessResult::Correct(atom)
+    } else if atom < *correct {
+        GuessResult::Higher(atom)
+    } else {
+        GuessResult::Lower(atom)
+    }
+}
+
+/// Compare a multi-valued atom, such as a set of roles or genres, with the correct atom
+/// The result is `Correct` if both hold the same items, `Partial` if they share
+/// some items and `Incorrect` if they share none. Duplicates and order are ignored
+/// # Arguments
+/// * `atom` - The atom to put in the result
+/// * `guess` - The items of the guessed atom
+/// * `correct` - The items of the correct atom
+///
+/// # Example
+///
+/// ```
+/// use rowdle::guesser::{overlap, GuessResult};
+///
+/// let roles = vec!["Top", "Jungle"];
+/// assert_eq!(overlap("Top", &["Top"], &["Top"]), GuessResult::Correct("Top"));
+/// assert_eq!(overlap("Top", &["Top"], &roles), GuessResult::Partial("Top"));
+/// assert_eq!(overlap("Mid", &["Mid"], &roles), GuessResult::Incorrect("Mid"));
+/// ```
+pub fn overlap<A: PartialEq, T: PartialEq>(
+    atom: A,
+    guess: impl IntoIterator<Item = T>,
+    correct: impl IntoIterator<Item = T>,
+) -> GuessResult<A> {
+    let guess: Vec<T> = guess.into_iter().collect();
+    let correct: Vec<T> = correct.into_iter().collect();
+
+    let shared = guess.iter().filter(|item| correct.contains(item)).count();
+    if shared == guess.len() && correct.iter().all(|item| guess.contains(item)) {
+        GuessResult::Correct(atom)
+    } else if shared > 0 {
+        GuessResult::Partial(atom)
+    } else {
+        GuessResult::Incorrect(atom)
+    }
+}
+
+fn marks<T: PartialEq>(guess: &[T], correct: &[T]) -> Vec<Mark> {
+    let mut marks = Vec::with_capacity(guess.len());
+    // atoms of the correct word that were not matched in place
+    let mut remaining = Vec::with_capacity(correct.len());
+
+    // check for correct guesses
+    for (i, atom) in guess.iter().enumerate() {
+        if correct.get(i) == Some(atom) {
+            marks.push(Mark::Correct);
+        } else {
+            marks.push(Mark::Incorrect);
+            remaining.extend(correct.get(i));
         }
+    }
+    remaining.extend(correct.iter().skip(guess.len()));
 
-        // initialize result vector with incorrect guesses
-        let mut result = vec![GuessResult::Incorrect(' '); self.len()];
-        let mut remaining_word = String::new();
-
-        // check for correct guesses
-        for (i, c) in self.chars().enumerate() {
-            if other.chars().nth(i) == Some(c) {
-                result[i] = GuessResult::Correct(c);
-            } else {
-                result[i] = GuessResult::Incorrect(c);
-                remaining_word.push(other.chars().nth(i).unwrap());
+    // check for misplaced guesses
+    for (mark, atom) in marks.iter_mut().zip(guess) {
+        if *mark == Mark::Incorrect {
+            if let Some(i) = remaining.iter().position(|r| *r == atom) {
+                remaining.swap_remove(i);
+                *mark = Mark::Misplaced;
             }
         }
+    }
 
-        // check for misplaced guesses
-        for (i, c) in result.iter_mut().enumerate() {
-            if let GuessResult::Incorrect(_) = c {
-                let char = self.chars().nth(i).unwrap();
-                if remaining_word.contains(char) {
-                    *c = GuessResult::Misplaced(char);
-                    remaining_word.remove(remaining_word.find(char).unwrap());
-                }
-            }
+    marks
+}
+
+/// Implement the `Guessable` trait for the `String` type
+/// Each Unicode scalar value (`char`) is scored as one atom
+/// Use [`GraphemeWord`] to score user-perceived characters instead
+impl Guessable<char> for String {
+    fn guess(&self, other: &Self) -> Guess<String, char> {
+        let guess = self.atoms();
+        let correct = other.atoms();
+
+        Guess {
+            guess: score(&guess, &correct),
+            word: self.clone(),
         }
+    }
+
+    fn atom_count(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn atoms(&self) -> Vec<char> {
+        self.chars().collect()
+    }
+
+    fn columns(&self) -> Vec<Column> {
+        Column::letters(self.atom_count())
+    }
+
+    fn pattern(&self, other: &Self) -> Option<Pattern> {
+        score_pattern(&self.atoms(), &other.atoms())
+    }
+}
+
+/// The `GraphemeWord` struct is a word scored per extended grapheme cluster
+/// Accented letters written with combining marks, Indic syllables and emoji
+/// sequences are each treated as a single atom
+#[derive(Debug, Clone, PartialEq, Default, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphemeWord(pub String);
+
+impl GraphemeWord {
+    /// Get the grapheme clusters of the word
+    pub fn graphemes(&self) -> impl Iterator<Item = &str> {
+        self.0.graphemes(true)
+    }
+
+    /// Get the word as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for GraphemeWord {
+    fn from(word: &str) -> Self {
+        Self(word.to_string())
+    }
+}
 
-        return Guess {
-            guess: result,
+impl From<String> for GraphemeWord {
+    fn from(word: String) -> Self {
+        Self(word)
+    }
+}
+
+impl std::fmt::Display for GraphemeWord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Implement the `Guessable` trait for the `GraphemeWord` type
+impl Guessable<String> for GraphemeWord {
+    fn guess(&self, other: &Self) -> Guess<GraphemeWord, String> {
+        let guess = self.atoms();
+        let correct = other.atoms();
+
+        Guess {
+            guess: score(&guess, &correct),
             word: self.clone(),
-        };
+        }
+    }
+
+    fn atom_count(&self) -> usize {
+        self.graphemes().count()
+    }
+
+    fn atoms(&self) -> Vec<String> {
+        self.graphemes().map(str::to_string).collect()
+    }
+
+    fn columns(&self) -> Vec<Column> {
+        Column::letters(self.atom_count())
+    }
+
+    fn pattern(&self, other: &Self) -> Option<Pattern> {
+        let guess: Vec<&str> = self.graphemes().collect();
+        let correct: Vec<&str> = other.graphemes().collect();
+        score_pattern(&guess, &correct)
     }
 }
 
@@ -116,4 +429,196 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_guess_multibyte() {
+        let guess = "über".to_string();
+        let correct = "rübe".to_string();
+        let res = guess.guess(&correct);
+        assert_eq!(
+            res.guess,
+            vec![
+                GuessResult::Misplaced('ü'),
+                GuessResult::Misplaced('b'),
+                GuessResult::Misplaced('e'),
+                GuessResult::Misplaced('r')
+            ]
+        );
+
+        let guess = "señor".to_string();
+        let correct = "señal".to_string();
+        let res = guess.guess(&correct);
+        assert_eq!(
+            res.guess,
+            vec![
+                GuessResult::Correct('s'),
+                GuessResult::Correct('e'),
+                GuessResult::Correct('ñ'),
+                GuessResult::Incorrect('o'),
+                GuessResult::Incorrect('r')
+            ]
+        );
+
+        let guess = "ñandú".to_string();
+        let res = guess.guess(&guess);
+        assert_eq!(res.guess.len(), 5);
+    }
+
+    #[test]
+    fn test_atom_count() {
+        assert_eq!("hello".to_string().atom_count(), 5);
+        assert_eq!("ñandú".to_string().atom_count(), 5);
+        assert_eq!(GraphemeWord::from("cafe\u{301}").atom_count(), 4);
+        assert_eq!(GraphemeWord::from("किताब").atom_count(), 3);
+    }
+
+    #[test]
+    fn test_guess_graphemes() {
+        // `e` followed by a combining acute accent is a single grapheme
+        let guess = GraphemeWord::from("cafe\u{301}s");
+        let correct = GraphemeWord::from("sabre\u{301}");
+        let res = guess.guess(&correct);
+        assert_eq!(
+            res.guess,
+            vec![
+                GuessResult::Incorrect("c".to_string()),
+                GuessResult::Correct("a".to_string()),
+                GuessResult::Incorrect("f".to_string()),
+                GuessResult::Misplaced("e\u{301}".to_string()),
+                GuessResult::Misplaced("s".to_string())
+            ]
+        );
+
+        let guess = GraphemeWord::from("किताब");
+        let correct = GraphemeWord::from("ताबकि");
+        let res = guess.guess(&correct);
+        assert_eq!(
+            res.guess,
+            vec![
+                GuessResult::Misplaced("कि".to_string()),
+                GuessResult::Misplaced("ता".to_string()),
+                GuessResult::Misplaced("ब".to_string())
+            ]
+        );
+
+        let guess = GraphemeWord::from("👍🏽👨\u{200d}👩\u{200d}👧🐶");
+        let correct = GraphemeWord::from("🐶👨\u{200d}👩\u{200d}👧👍");
+        let res = guess.guess(&correct);
+        assert_eq!(
+            res.guess,
+            vec![
+                GuessResult::Incorrect("👍🏽".to_string()),
+                GuessResult::Correct("👨\u{200d}👩\u{200d}👧".to_string()),
+                GuessResult::Misplaced("🐶".to_string())
+            ]
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default, rowdle_derive::Guessable)]
+    struct Champion {
+        name: String,
+        #[guessable(numeric, unit = "year")]
+        release_year: u16,
+        #[guessable(set, name = "Positions")]
+        positions: Vec<&'static str>,
+        #[guessable(skip)]
+        title: String,
+    }
+
+    #[test]
+    fn test_derive() {
+        let garen = Champion {
+            name: "Garen".to_string(),
+            release_year: 2010,
+            positions: vec!["Top"],
+            title: "The Might of Demacia".to_string(),
+        };
+        let sett = Champion {
+            name: "Sett".to_string(),
+            release_year: 2020,
+            positions: vec!["Top", "Support"],
+            title: "The Boss".to_string(),
+        };
+
+        assert_eq!(garen.atom_count(), 3);
+        assert_eq!(
+            garen.guess(&sett).guess,
+            vec![
+                GuessResult::Incorrect("Garen".to_string()),
+                GuessResult::Higher("2010".to_string()),
+                GuessResult::Partial("Top".to_string()),
+            ]
+        );
+        assert_eq!(
+            sett.guess(&garen).guess,
+            vec![
+                GuessResult::Incorrect("Sett".to_string()),
+                GuessResult::Lower("2020".to_string()),
+                GuessResult::Partial("Top, Support".to_string()),
+            ]
+        );
+        assert!(garen
+            .guess(&garen)
+            .guess
+            .iter()
+            .all(|r| matches!(r, GuessResult::Correct(_))));
+
+        #[derive(Debug, Clone, PartialEq, rowdle_derive::Guessable)]
+        struct Pair(#[guessable(numeric)] u8, char);
+        assert_eq!(
+            Pair(1, 'a').guess(&Pair(1, 'b')).guess,
+            vec![
+                GuessResult::Correct("1".to_string()),
+                GuessResult::Incorrect("a".to_string())
+            ]
+        );
+        assert_eq!(
+            Pair(1, 'a').columns(),
+            vec![
+                Column::new("0", ColumnKind::Number),
+                Column::new("1", ColumnKind::Category)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_derive_numeric() {
+        #[derive(Debug, Clone, PartialEq, rowdle_derive::Guessable)]
+        struct Price(#[guessable(numeric)] u32);
+
+        // the fields are compared as numbers before they are rendered
+        assert_eq!(
+            Price(9).guess(&Price(50)).guess,
+            vec![GuessResult::Higher("9".to_string())]
+        );
+        assert_eq!(
+            Price(100).guess(&Price(50)).guess,
+            vec![GuessResult::Lower("100".to_string())]
+        );
+
+        // but the rendered atoms do not order as numbers
+        assert!("100" < "50");
+        let mut knowledge = crate::knowledge::Knowledge::new().with_columns(&Price(50).columns());
+        knowledge.add(&Price(100).guess(&Price(50)));
+        assert!(knowledge.is_consistent_ordered(&Price(50).atoms()));
+        assert!(!knowledge.is_consistent_ordered(&Price(150).atoms()));
+    }
+
+    #[test]
+    fn test_columns() {
+        let garen = Champion::default();
+        assert_eq!(
+            garen.columns(),
+            vec![
+                Column::new("name", ColumnKind::Category),
+                Column::new("release_year", ColumnKind::Number).with_unit("year"),
+                Column::new("Positions", ColumnKind::Set),
+            ]
+        );
+        assert_eq!(garen.columns()[1].label(), "release_year (year)");
+
+        assert_eq!("abc".to_string().columns(), Column::letters(3));
+        assert_eq!(GraphemeWord::from("ab").columns()[1].name, "2");
+        assert_eq!(garen.columns().len(), garen.atom_count());
+    }
 }
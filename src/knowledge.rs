@@ -0,0 +1,501 @@
+use std::fmt::Display;
+
+use crate::guesser::{Column, ColumnKind, Guess, GuessResult, Guessable};
+
+/// The `AtomCount` struct represents the known bounds on how often an atom
+/// occurs in the correct word
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AtomCount<T: PartialEq> {
+    pub atom: T,
+    /// The atom occurs at least this many times
+    pub min: usize,
+    /// The atom occurs at most this many times, if known
+    pub max: Option<usize>,
+}
+
+/// The `Knowledge` struct represents everything that can be deduced about the
+/// correct word from a set of guesses
+///
+/// # Example
+///
+/// ```
+/// use rowdle::knowledge::Knowledge;
+/// use rowdle::Guessable;
+///
+/// let guess = "world".to_string().guess(&"hello".to_string());
+/// let knowledge = Knowledge::from_guesses(&[guess]);
+///
+/// assert_eq!(knowledge.known(3), Some(&'l'));
+/// assert!(knowledge.is_excluded(1, &'o'));
+/// assert_eq!(knowledge.min_count(&'o'), 1);
+/// assert_eq!(knowledge.max_count(&'w'), Some(0));
+/// assert!(knowledge.is_consistent(&"hello".to_string()));
+/// assert!(!knowledge.is_consistent(&"world".to_string()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Knowledge<T: PartialEq> {
+    known: Vec<Option<T>>,
+    excluded: Vec<Vec<T>>,
+    counts: Vec<AtomCount<T>>,
+    /// The atoms the correct atom of each position is known to be higher than
+    above: Vec<Vec<T>>,
+    /// The atoms the correct atom of each position is known to be lower than
+    below: Vec<Vec<T>>,
+    /// The atoms known to share some items with the correct atom of each position
+    partial: Vec<Vec<T>>,
+    /// The kind of each position, empty if the columns are not described
+    #[cfg_attr(feature = "serde", serde(default))]
+    kinds: Vec<ColumnKind>,
+}
+
+impl<T: PartialEq> Default for Knowledge<T> {
+    fn default() -> Self {
+        Self {
+            known: vec![],
+            excluded: vec![],
+            counts: vec![],
+            above: vec![],
+            below: vec![],
+            partial: vec![],
+            kinds: vec![],
+        }
+    }
+}
+
+impl<T: PartialEq + Clone> Knowledge<T> {
+    /// Create an empty knowledge base
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the columns of the words
+    /// Count deductions only apply to letter columns, other columns only exclude
+    /// a rejected atom from its own position. Undescribed positions count as letters
+    /// # Arguments
+    /// * `columns` - The columns of the words, see [`Guessable::columns`]
+    pub fn with_columns(mut self, columns: &[Column]) -> Self {
+        self.kinds = columns.iter().map(|c| c.kind).collect();
+        self
+    }
+
+    /// Check if the atom at a position takes part in count deductions
+    fn is_counted(&self, position: usize) -> bool {
+        self.kinds
+            .get(position)
+            .is_none_or(|kind| *kind == ColumnKind::Letter)
+    }
+
+    /// Build the knowledge base from a history of guesses
+    /// # Arguments
+    /// * `guesses` - The guesses made so far
+    pub fn from_guesses<G: PartialEq + Clone>(guesses: &[Guess<G, T>]) -> Self {
+        let mut knowledge = Self::new();
+        for guess in guesses {
+            knowledge.add(guess);
+        }
+        knowledge
+    }
+
+    /// Add the deductions from a single guess
+    /// # Arguments
+    /// * `guess` - The scored guess
+    pub fn add<G: PartialEq + Clone>(&mut self, guess: &Guess<G, T>) {
+        if self.known.len() < guess.guess.len() {
+            self.known.resize(guess.guess.len(), None);
+            self.excluded.resize(guess.guess.len(), vec![]);
+            self.above.resize(guess.guess.len(), vec![]);
+            self.below.resize(guess.guess.len(), vec![]);
+            self.partial.resize(guess.guess.len(), vec![]);
+        }
+
+        // per position deductions
+        for (i, result) in guess.guess.iter().enumerate() {
+            match result {
+                GuessResult::Correct(a) => self.known[i] = Some(a.clone()),
+                GuessResult::Misplaced(a) | GuessResult::Incorrect(a) => {
+                    push_unique(&mut self.excluded[i], a);
+                }
+                GuessResult::Higher(a) => {
+                    push_unique(&mut self.excluded[i], a);
+                    push_unique(&mut self.above[i], a);
+                }
+                GuessResult::Lower(a) => {
+                    push_unique(&mut self.excluded[i], a);
+                    push_unique(&mut self.below[i], a);
+                }
+                GuessResult::Partial(a) => {
+                    push_unique(&mut self.excluded[i], a);
+                    push_unique(&mut self.partial[i], a);
+                }
+                GuessResult::Empty | GuessResult::Custom(_) => {}
+            }
+        }
+
+        // count deductions
+        // an atom that is both found and marked incorrect occurs exactly as often as it was found
+        // directional and partial results, and attribute columns, only say something about their own position
+        let counted: Vec<&GuessResult<T>> = guess
+            .guess
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.is_counted(*i))
+            .map(|(_, result)| result)
+            .collect();
+        let mut seen: Vec<&T> = vec![];
+        for result in &counted {
+            let atom = match result {
+                GuessResult::Correct(a) | GuessResult::Misplaced(a) | GuessResult::Incorrect(a) => {
+                    a
+                }
+                GuessResult::Empty
+                | GuessResult::Custom(_)
+                | GuessResult::Higher(_)
+                | GuessResult::Lower(_)
+                | GuessResult::Partial(_) => continue,
+            };
+            if seen.contains(&atom) {
+                continue;
+            }
+            seen.push(atom);
+
+            let mut found = 0;
+            let mut rejected = false;
+            for other in &counted {
+                match other {
+                    GuessResult::Correct(a) | GuessResult::Misplaced(a) if a == atom => found += 1,
+                    GuessResult::Incorrect(a) if a == atom => rejected = true,
+                    _ => {}
+                }
+            }
+
+            let count = self.count_mut(atom);
+            count.min = count.min.max(found);
+            if rejected {
+                count.max = Some(count.max.map_or(found, |max| max.min(found)));
+            }
+        }
+    }
+
+    fn count_mut(&mut self, atom: &T) -> &mut AtomCount<T> {
+        match self.counts.iter().position(|c| c.atom == *atom) {
+            Some(i) => &mut self.counts[i],
+            None => {
+                self.counts.push(AtomCount {
+                    atom: atom.clone(),
+                    min: 0,
+                    max: None,
+                });
+                self.counts.last_mut().unwrap()
+            }
+        }
+    }
+
+    /// The number of positions the knowledge covers
+    pub fn len(&self) -> usize {
+        self.known.len()
+    }
+
+    /// Check if nothing is known yet
+    pub fn is_empty(&self) -> bool {
+        self.known.is_empty() && self.counts.is_empty()
+    }
+
+    /// Get the atom known to be at a position
+    pub fn known(&self, position: usize) -> Option<&T> {
+        self.known.get(position).and_then(Option::as_ref)
+    }
+
+    /// Get the atoms known not to be at a position
+    pub fn excluded(&self, position: usize) -> &[T] {
+        self.excluded.get(position).map_or(&[], Vec::as_slice)
+    }
+
+    /// Check if an atom is known not to be at a position
+    pub fn is_excluded(&self, position: usize, atom: &T) -> bool {
+        self.excluded(position).contains(atom)
+    }
+
+    /// Get the atoms the correct atom at a position is known to be higher than
+    pub fn above(&self, position: usize) -> &[T] {
+        self.above.get(position).map_or(&[], Vec::as_slice)
+    }
+
+    /// Get the atoms the correct atom at a position is known to be lower than
+    pub fn below(&self, position: usize) -> &[T] {
+        self.below.get(position).map_or(&[], Vec::as_slice)
+    }
+
+    /// Get the atoms known to share some, but not all, items with the correct atom at a position
+    pub fn partial(&self, position: usize) -> &[T] {
+        self.partial.get(position).map_or(&[], Vec::as_slice)
+    }
+
+    /// Get the count bounds of every atom seen so far
+    pub fn counts(&self) -> &[AtomCount<T>] {
+        &self.counts
+    }
+
+    /// The minimum number of times an atom occurs in the correct word
+    pub fn min_count(&self, atom: &T) -> usize {
+        self.counts
+            .iter()
+            .find(|c| c.atom == *atom)
+            .map_or(0, |c| c.min)
+    }
+
+    /// The maximum number of times an atom occurs in the correct word, if known
+    pub fn max_count(&self, atom: &T) -> Option<usize> {
+        self.counts
+            .iter()
+            .find(|c| c.atom == *atom)
+            .and_then(|c| c.max)
+    }
+
+    /// Check if a word could still be the correct word
+    /// The bounds of `Higher` and `Lower` results are not checked, see
+    /// [`Knowledge::is_consistent_ordered`]
+    /// # Arguments
+    /// * `candidate` - The word to check
+    pub fn is_consistent<G: Guessable<T>>(&self, candidate: &G) -> bool {
+        self.is_consistent_atoms(&candidate.atoms())
+    }
+
+    /// Check if a sequence of atoms could still be the correct word
+    /// # Arguments
+    /// * `atoms` - The atoms to check
+    pub fn is_consistent_atoms(&self, atoms: &[T]) -> bool {
+        if !self.known.is_empty() && atoms.len() != self.known.len() {
+            return false;
+        }
+
+        for (i, atom) in atoms.iter().enumerate() {
+            if let Some(known) = self.known(i) {
+                if known != atom {
+                    return false;
+                }
+            }
+            if self.is_excluded(i, atom) {
+                return false;
+            }
+        }
+
+        self.counts.iter().all(|c| {
+            let n = atoms
+                .iter()
+                .enumerate()
+                .filter(|(i, a)| self.is_counted(*i) && **a == c.atom)
+                .count();
+            n >= c.min && c.max.is_none_or(|max| n <= max)
+        })
+    }
+}
+
+impl<T: PartialEq + Clone + Display> Knowledge<T> {
+    /// Check if a sequence of atoms could still be the correct word, also
+    /// checking the bounds of `Higher` and `Lower` results
+    /// The bounds only apply to `Number` columns, comparing the atoms as the
+    /// numbers they display. Atoms that are not numbers are not bounded
+    /// # Arguments
+    /// * `atoms` - The atoms to check
+    pub fn is_consistent_ordered(&self, atoms: &[T]) -> bool {
+        self.is_consistent_atoms(atoms)
+            && atoms.iter().enumerate().all(|(i, atom)| {
+                if self.kinds.get(i) != Some(&ColumnKind::Number) {
+                    return true;
+                }
+                let Some(n) = as_number(atom) else {
+                    return true;
+                };
+                self.above(i).iter().filter_map(as_number).all(|a| n > a)
+                    && self.below(i).iter().filter_map(as_number).all(|b| n < b)
+            })
+    }
+}
+
+/// Read an atom as the number it displays
+fn as_number<T: Display>(atom: &T) -> Option<f64> {
+    atom.to_string().trim().parse().ok()
+}
+
+fn push_unique<T: PartialEq + Clone>(atoms: &mut Vec<T>, atom: &T) {
+    if !atoms.contains(atom) {
+        atoms.push(atom.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn knowledge(guesses: &[(&str, &str)]) -> Knowledge<char> {
+        let guesses: Vec<_> = guesses
+            .iter()
+            .map(|(guess, correct)| guess.to_string().guess(&correct.to_string()))
+            .collect();
+        Knowledge::from_guesses(&guesses)
+    }
+
+    #[test]
+    fn test_knowledge() {
+        let knowledge = knowledge(&[("world", "hello"), ("lolly", "hello")]);
+
+        assert_eq!(knowledge.len(), 5);
+        assert_eq!(knowledge.known(0), None);
+        assert_eq!(knowledge.known(2), Some(&'l'));
+        assert_eq!(knowledge.known(3), Some(&'l'));
+        assert!(knowledge.is_excluded(0, &'l'));
+        assert!(knowledge.is_excluded(1, &'o'));
+
+        // lolly has three l's but only two are found
+        assert_eq!(knowledge.min_count(&'l'), 2);
+        assert_eq!(knowledge.max_count(&'l'), Some(2));
+        assert_eq!(knowledge.min_count(&'o'), 1);
+        assert_eq!(knowledge.max_count(&'o'), None);
+        assert_eq!(knowledge.max_count(&'y'), Some(0));
+
+        assert!(knowledge.is_consistent(&"hello".to_string()));
+        assert!(knowledge.is_consistent(&"jello".to_string()));
+        assert!(!knowledge.is_consistent(&"helly".to_string()));
+        assert!(!knowledge.is_consistent(&"hell".to_string()));
+        assert!(!knowledge.is_consistent(&"ollie".to_string()));
+    }
+
+    #[test]
+    fn test_knowledge_duplicates() {
+        // the second e is incorrect so the answer has exactly one e
+        let knowledge = knowledge(&[("geese", "those")]);
+
+        assert_eq!(knowledge.min_count(&'e'), 1);
+        assert_eq!(knowledge.max_count(&'e'), Some(1));
+        assert!(knowledge.is_consistent(&"those".to_string()));
+        assert!(knowledge.is_consistent(&"prose".to_string()));
+        assert!(!knowledge.is_consistent(&"eerse".to_string()));
+    }
+
+    #[test]
+    fn test_knowledge_empty() {
+        let knowledge = Knowledge::<char>::new();
+        assert!(knowledge.is_empty());
+        assert!(knowledge.is_consistent(&"anything".to_string()));
+    }
+
+    #[test]
+    fn test_knowledge_directional() {
+        let guess = Guess {
+            word: "17".to_string(),
+            guess: vec![GuessResult::Higher(1), GuessResult::Lower(7)],
+        };
+        let columns = [
+            Column::new("0", ColumnKind::Number),
+            Column::new("1", ColumnKind::Number),
+        ];
+        let mut knowledge = Knowledge::new().with_columns(&columns);
+        knowledge.add(&guess);
+
+        assert_eq!(knowledge.above(0), [1]);
+        assert_eq!(knowledge.below(1), [7]);
+        assert!(knowledge.is_excluded(0, &1));
+        assert_eq!(knowledge.max_count(&1), None);
+
+        assert!(knowledge.is_consistent_ordered(&[4, 2]));
+        assert!(knowledge.is_consistent_atoms(&[0, 9]));
+        assert!(!knowledge.is_consistent_ordered(&[0, 2]));
+        assert!(!knowledge.is_consistent_ordered(&[4, 9]));
+        assert!(!knowledge.is_consistent_ordered(&[1, 2]));
+
+        // the bounds only apply to number columns
+        let knowledge = Knowledge::from_guesses(&[guess]);
+        assert!(knowledge.is_consistent_ordered(&[0, 9]));
+    }
+
+    #[test]
+    fn test_knowledge_partial() {
+        let guess = Guess {
+            word: "champion".to_string(),
+            guess: vec![
+                GuessResult::Correct("Garen".to_string()),
+                GuessResult::Partial("Top, Mid".to_string()),
+            ],
+        };
+        let knowledge = Knowledge::from_guesses(&[guess]);
+
+        assert_eq!(knowledge.partial(1), ["Top, Mid".to_string()]);
+        assert!(knowledge.is_excluded(1, &"Top, Mid".to_string()));
+        assert_eq!(knowledge.min_count(&"Top, Mid".to_string()), 0);
+        assert!(knowledge.is_consistent_atoms(&["Garen".to_string(), "Top".to_string()]));
+        assert!(!knowledge.is_consistent_atoms(&["Garen".to_string(), "Top, Mid".to_string()]));
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default, rowdle_derive::Guessable)]
+    struct Roles {
+        primary: String,
+        secondary: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default, rowdle_derive::Guessable)]
+    struct Item {
+        name: String,
+        #[guessable(numeric)]
+        price: u32,
+    }
+
+    #[test]
+    fn test_knowledge_numeric() -> Result<(), crate::error::WordleError<Item, String>> {
+        let item = |name: &str, price| Item {
+            name: name.to_string(),
+            price,
+        };
+        let answer = item("pen", 50);
+        let word_list = vec![
+            answer.clone(),
+            item("cup", 100),
+            item("hat", 150),
+            item("mug", 9),
+        ];
+        let mut game = crate::Game::new(6, answer.clone(), word_list);
+
+        // the prices have different digit counts, so they do not order as strings
+        let res = game.guess(item("cup", 100))?;
+        assert_eq!(res.guess[1], GuessResult::Lower("100".to_string()));
+
+        let knowledge = game.knowledge();
+        assert!(game.is_candidate(&answer));
+        assert!(knowledge.is_consistent(&answer));
+        assert!(knowledge.is_consistent_ordered(&answer.atoms()));
+        assert!(knowledge.is_consistent_ordered(&item("mug", 9).atoms()));
+        assert!(!knowledge.is_consistent_ordered(&item("hat", 150).atoms()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_knowledge_columns() -> Result<(), crate::error::WordleError<Roles, String>> {
+        let roles = |primary: &str, secondary: &str| Roles {
+            primary: primary.to_string(),
+            secondary: secondary.to_string(),
+        };
+        let answer = roles("Mid", "Top");
+        let word_list = vec![answer.clone(), roles("Top", "Mid"), roles("Top", "Bot")];
+        let mut game = crate::Game::new(6, answer.clone(), word_list);
+
+        // Top and Mid are both rejected, but only at their own position
+        let res = game.guess(roles("Top", "Mid"))?;
+        assert_eq!(
+            res.guess,
+            vec![
+                GuessResult::Incorrect("Top".to_string()),
+                GuessResult::Incorrect("Mid".to_string())
+            ]
+        );
+
+        let knowledge = game.knowledge();
+        assert_eq!(knowledge.max_count(&"Top".to_string()), None);
+        assert!(knowledge.is_consistent(&answer));
+        assert!(!knowledge.is_consistent(&roles("Top", "Bot")));
+        assert_eq!(game.remaining_count(), 1);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,28 @@
-pub use guesser::{Guess, GuessResult, Guessable};
+// lets the code generated by `rowdle-derive` refer to `::rowdle` inside this crate
+extern crate self as rowdle;
+
+pub use dictionary::Dictionary;
+pub use guesser::{Column, ColumnKind, GraphemeWord, Guess, GuessResult, Guessable};
+pub use pattern::Pattern;
+#[cfg(feature = "derive")]
+pub use rowdle_derive::Guessable;
 use std::fmt::Debug;
 
+pub mod absurdle;
+pub mod daily;
+pub mod dictionary;
 pub mod error;
+pub mod fibble;
 pub mod guesser;
+pub mod knowledge;
+pub mod matrix;
+pub mod multi;
+pub mod pattern;
+mod rng;
+pub mod share;
+pub mod solver;
+pub mod wordlist;
+pub mod xordle;
 
 /// A game of Wordle
 ///
@@ -14,7 +34,7 @@ pub mod guesser;
 /// let word_list = vec!["hello".to_string(), "world".to_string()];
 /// let word = "hello".to_string();
 /// let mut game = Game::new(5, word, word_list);
-
+///
 /// let res = game.guess("hello".to_string()).unwrap();
 /// assert_eq!(
 ///     res.guess,
@@ -27,43 +47,130 @@ pub mod guesser;
 ///     ]
 /// );
 /// assert!(game.game_over());
-///
-pub struct Game<T: PartialEq + Clone, G: Guessable<T> + Default + Debug> {
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Game<
+    T: PartialEq + Clone + Debug,
+    G: Guessable<T> + Default + Debug,
+    D: Dictionary<G> = Vec<G>,
+> {
     max_tries: u8,
     correct_word: G,
-    word_list: Vec<G>,
+    word_list: D,
+    answers: Option<Vec<G>>,
     guesses: Vec<Guess<G, T>>,
+    hard_mode: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    abandoned: bool,
 }
 
-impl<T: PartialEq + Clone, G: Guessable<T> + Default + Debug> Game<T, G> {
+impl<T: PartialEq + Clone + Debug, G: Guessable<T> + Default + Debug, D: Dictionary<G>>
+    Game<T, G, D>
+{
     /// Create a new game of Wordle
     /// # Arguments
     /// * `max_tries` - The maximum number of tries allowed
     /// * `correct_word` - The correct word to guess
-    /// * `word_list` - A list of words that can be guessed
-    pub fn new(max_tries: u8, correct_word: G, word_list: Vec<G>) -> Self {
+    /// * `word_list` - A dictionary of words that can be guessed, also used as the list of possible answers
+    pub fn new(max_tries: u8, correct_word: G, word_list: D) -> Self {
         Self {
             max_tries,
             correct_word,
             word_list,
+            answers: None,
             guesses: vec![],
+            hard_mode: false,
+            abandoned: false,
         }
     }
 
+    /// Rebuild a game from the words guessed so far
+    /// Every word is guessed again in order, so the history is validated like live guesses
+    /// # Arguments
+    /// * `max_tries` - The maximum number of tries allowed
+    /// * `correct_word` - The correct word to guess
+    /// * `word_list` - A dictionary of words that can be guessed, also used as the list of possible answers
+    /// * `history` - The words guessed so far, in order
+    /// # Returns
+    /// The game, or the error of the first guess that is not valid
+    pub fn from_history(
+        max_tries: u8,
+        correct_word: G,
+        word_list: D,
+        history: impl IntoIterator<Item = G>,
+    ) -> Result<Self, error::WordleError<G, T>> {
+        Self::new(max_tries, correct_word, word_list).with_history(history)
+    }
+
+    /// Replay the words guessed so far on a configured game
+    /// Call it after the other `with_*` methods, so that answers and hard mode apply to the history
+    /// # Arguments
+    /// * `history` - The words guessed so far, in order
+    /// # Returns
+    /// The game, or the error of the first guess that is not valid
+    pub fn with_history(
+        mut self,
+        history: impl IntoIterator<Item = G>,
+    ) -> Result<Self, error::WordleError<G, T>> {
+        for word in history {
+            self.guess(word)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Set the list of words that can be the correct word
+    /// The word list then only decides which guesses are allowed, and the
+    /// answers are used for candidate counting, solvers and daily selection
+    /// Answers are always allowed as guesses
+    pub fn with_answers(mut self, answers: Vec<G>) -> Self {
+        self.answers = Some(answers);
+        self
+    }
+
+    /// Get the words that can be the correct word
+    pub fn answers(&self) -> Box<dyn Iterator<Item = &G> + '_> {
+        match &self.answers {
+            Some(answers) => Box::new(answers.iter()),
+            None => self.word_list.words(),
+        }
+    }
+
+    /// Enable or disable hard mode
+    /// In hard mode every revealed hint must be used in subsequent guesses:
+    /// correct atoms must stay in place and misplaced atoms must be reused
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Check if hard mode is enabled
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
     /// Make a guess
     /// # Arguments
     /// * `word` - The word to guess
-    pub fn guess(&mut self, word: G) -> Result<Guess<G, T>, error::WordleError<G>> {
-        if self.max_tries == 0 {
-            return Err(error::WordleError::MaxTriesExceeded);
+    pub fn guess(&mut self, word: G) -> Result<Guess<G, T>, error::WordleError<G, T>> {
+        match self.status() {
+            Status::InProgress => {}
+            Status::Abandoned => return Err(error::WordleError::GameAbandoned),
+            Status::Won => return Err(error::WordleError::GameWon),
+            Status::Lost => return Err(error::WordleError::MaxTriesExceeded),
         }
 
-        if !self.word_list.contains(&word) {
-            return Err(error::WordleError::InvalidWord(word));
-        }
+        let word = check_guess(
+            word,
+            self.correct_word.atom_count(),
+            &self.word_list,
+            self.answers.as_deref(),
+            |w| self.is_word_guessed(w),
+        )?;
 
-        if self.is_word_guessed(&word) {
-            return Err(error::WordleError::WordAlreadyGuessed(word));
+        if self.hard_mode {
+            self.check_hard_mode(&word)?;
         }
 
         let res = word.guess(&self.correct_word);
@@ -71,27 +178,126 @@ impl<T: PartialEq + Clone, G: Guessable<T> + Default + Debug> Game<T, G> {
         Ok(self.guesses.last().unwrap().clone())
     }
 
+    /// Check that a word uses every hint revealed so far
+    /// Correct atoms must be in the same position and misplaced atoms must be present
+    fn check_hard_mode(&self, word: &G) -> Result<(), error::WordleError<G, T>> {
+        let atoms = word.atoms();
+
+        for guess in &self.guesses {
+            // the atoms not pinned by a correct hint, available for misplaced hints
+            let mut remaining: Vec<&T> = vec![];
+
+            for (position, result) in guess.guess.iter().enumerate() {
+                match result {
+                    GuessResult::Correct(atom) if atoms.get(position) != Some(atom) => {
+                        return Err(error::WordleError::HardModeCorrect {
+                            atom: atom.clone(),
+                            position,
+                        });
+                    }
+                    GuessResult::Correct(_) => {}
+                    _ => remaining.extend(atoms.get(position)),
+                }
+            }
+
+            for result in &guess.guess {
+                if let GuessResult::Misplaced(atom) = result {
+                    match remaining.iter().position(|a| *a == atom) {
+                        Some(i) => {
+                            remaining.swap_remove(i);
+                        }
+                        None => return Err(error::WordleError::HardModeMisplaced(atom.clone())),
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get everything that can be deduced about the correct word from the guesses so far
+    pub fn knowledge(&self) -> knowledge::Knowledge<T> {
+        let mut knowledge = knowledge::Knowledge::new().with_columns(&self.columns());
+        for guess in &self.guesses {
+            knowledge.add(guess);
+        }
+        knowledge
+    }
+
+    /// Check if a word could still be the correct word
+    /// A word is a candidate if guessing against it reproduces every recorded guess
+    pub fn is_candidate(&self, word: &G) -> bool {
+        self.guesses
+            .iter()
+            .all(|g| g.word.guess(word).guess == g.guess)
+    }
+
+    /// Get the answers that could still be the correct word
+    pub fn remaining_candidates(&self) -> Vec<&G> {
+        self.answers().filter(|w| self.is_candidate(w)).collect()
+    }
+
+    /// Get the number of answers that could still be the correct word
+    pub fn remaining_count(&self) -> usize {
+        self.answers().filter(|w| self.is_candidate(w)).count()
+    }
+
+    /// Get the answers of a pattern matrix that could still be the correct word
+    /// Guesses are looked up in the matrix instead of being scored again
+    /// # Arguments
+    /// * `matrix` - A pattern matrix whose answers are this game's answers
+    pub fn remaining_candidates_cached<'a>(
+        &self,
+        matrix: &'a matrix::PatternMatrix<G>,
+    ) -> Vec<&'a G>
+    where
+        G: std::hash::Hash + Eq,
+    {
+        matrix.remaining(&self.guesses)
+    }
+
     /// Check if a word has been guessed
     pub fn is_word_guessed(&self, word: &G) -> bool {
         self.guesses.iter().any(|g| g.word == *word)
     }
 
+    /// Get the state of the game
+    /// A game is won once the correct word has been guessed, lost once every
+    /// try has been used without guessing it, and abandoned if the player gave up first
+    pub fn status(&self) -> Status {
+        if self.is_word_guessed(&self.correct_word) {
+            Status::Won
+        } else if self.guesses.len() >= self.max_tries as usize {
+            Status::Lost
+        } else if self.abandoned {
+            Status::Abandoned
+        } else {
+            Status::InProgress
+        }
+    }
+
     /// Check if the game is won
     /// A game is won if the correct word has been guessed
     pub fn won(&self) -> bool {
-        self.guesses.iter().any(|g| g.word == self.correct_word)
+        self.status() == Status::Won
     }
 
     /// Check if the game is lost
     /// A game is lost if the maximum number of tries has been exceeded
     pub fn lost(&self) -> bool {
-        self.guesses.len() == self.max_tries.into() && !self.won()
+        self.status() == Status::Lost
+    }
+
+    /// Check if the game is abandoned
+    /// A game is abandoned if the player gave up before it was won or lost
+    pub fn abandoned(&self) -> bool {
+        self.status() == Status::Abandoned
     }
 
     /// Check if the game is over
-    /// A game is over if the game is won or lost
+    /// A game is over if the game is won, lost or abandoned
     pub fn game_over(&self) -> bool {
-        self.won() || self.lost()
+        self.status() != Status::InProgress
     }
 
     /// Get the correct word
@@ -99,6 +305,26 @@ impl<T: PartialEq + Clone, G: Guessable<T> + Default + Debug> Game<T, G> {
         &self.correct_word
     }
 
+    /// Get the maximum number of tries allowed
+    pub fn max_tries(&self) -> u8 {
+        self.max_tries
+    }
+
+    /// Get the number of guesses left before the game is lost
+    pub fn remaining_tries(&self) -> u8 {
+        self.max_tries.saturating_sub(self.guesses.len() as u8)
+    }
+
+    /// Get the guesses made so far, in order
+    pub fn guesses(&self) -> &[Guess<G, T>] {
+        &self.guesses
+    }
+
+    /// Get the dictionary of words that can be guessed
+    pub fn word_list(&self) -> &D {
+        &self.word_list
+    }
+
     /// get a 2d vector of the board
     /// # Arguments
     /// * `pad` - The number of empty guesses to pad the board with
@@ -106,43 +332,224 @@ impl<T: PartialEq + Clone, G: Guessable<T> + Default + Debug> Game<T, G> {
     /// # Returns
     /// A 2d vector of the board
     pub fn board(&self, pad: Option<u32>, buffer: Option<Guess<G, T>>) -> Vec<Guess<G, T>> {
-        let mut guesses = self.guesses.clone();
-
-        if let Some(buffer) = buffer {
-            let len = self.correct_word.guess(&self.correct_word).guess.len();
-            // pad the buffer guess with empty guesses
-            let n = len - buffer.guess.len();
-            let mut guess = buffer.clone();
-            for _ in 0..n {
-                guess.guess.push(GuessResult::Empty);
-            }
+        board_rows(
+            &self.guesses,
+            self.correct_word.atom_count(),
+            self.max_tries,
+            pad,
+            buffer,
+        )
+    }
 
-            guesses.push(guess);
+    /// Render the game as share text with the classic dark palette
+    /// See [`share::share`] for the format
+    pub fn share(&self) -> String {
+        share::share(self, &share::ShareOptions::new())
+    }
+
+    /// Render the game as share text
+    /// # Arguments
+    /// * `options` - The palette, title and custom glyphs to use
+    pub fn share_with(&self, options: &share::ShareOptions<T>) -> String {
+        share::share(self, options)
+    }
+
+    /// Get a description of each atom position of the words
+    /// Empty if the word type does not describe its columns
+    pub fn columns(&self) -> Vec<guesser::Column> {
+        self.correct_word.columns()
+    }
+
+    /// Get a view of the game that does not contain the correct word
+    /// Useful to send a game to clients that should not be able to read the answer
+    pub fn hide_answer(&self) -> HiddenGame<T, G> {
+        HiddenGame {
+            max_tries: self.max_tries,
+            guesses: self.guesses.clone(),
+            hard_mode: self.hard_mode,
+            columns: self.columns(),
+            status: self.status(),
         }
+    }
 
-        if let Some(pad) = pad {
-            let num_cell = self.max_tries as u32 - guesses.len() as u32;
-            let n = pad.min(num_cell);
-
-            for _ in 0..n {
-                guesses.push(Guess {
-                    word: G::default(),
-                    guess: vec![
-                        GuessResult::Empty;
-                        self.correct_word.guess(&self.correct_word).guess.len()
-                    ],
-                });
-            }
+    /// Give up the game and reveal the correct word
+    /// The guesses are kept for the board, share text and stats
+    /// A game that is already over keeps its status
+    pub fn end_game(&mut self) -> &G {
+        self.abandoned = true;
+        &self.correct_word
+    }
+}
+
+/// The `Status` enum represents the state of a game
+/// `InProgress` is the only state that accepts guesses, the others are final
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Status {
+    #[default]
+    InProgress,
+    Won,
+    Lost,
+    Abandoned,
+}
+
+/// The `Playable` trait is the surface shared by the single board game modes
+/// Front ends written against it can swap between `Game`, `absurdle::Absurdle`,
+/// `fibble::Fibble` and `xordle::Xordle`
+pub trait Playable<T: PartialEq + Clone + Debug, G: PartialEq + Clone + Debug> {
+    /// Make a guess
+    fn guess(&mut self, word: G) -> Result<Guess<G, T>, error::WordleError<G, T>>;
+
+    /// Check if the game is won
+    fn won(&self) -> bool;
+
+    /// Check if the game is lost
+    fn lost(&self) -> bool;
+
+    /// Get the state of the game
+    /// The default implementation derives it from `won` and `lost`, for modes that cannot be abandoned
+    fn status(&self) -> Status {
+        if self.won() {
+            Status::Won
+        } else if self.lost() {
+            Status::Lost
+        } else {
+            Status::InProgress
+        }
+    }
+
+    /// Check if the game is over
+    fn game_over(&self) -> bool {
+        self.status() != Status::InProgress
+    }
+
+    /// get a 2d vector of the board
+    /// See [`Game::board`] for the arguments
+    fn board(&self, pad: Option<u32>, buffer: Option<Guess<G, T>>) -> Vec<Guess<G, T>>;
+}
+
+impl<T: PartialEq + Clone + Debug, G: Guessable<T> + Default + Debug, D: Dictionary<G>>
+    Playable<T, G> for Game<T, G, D>
+{
+    fn guess(&mut self, word: G) -> Result<Guess<G, T>, error::WordleError<G, T>> {
+        Game::guess(self, word)
+    }
+
+    fn won(&self) -> bool {
+        Game::won(self)
+    }
+
+    fn lost(&self) -> bool {
+        Game::lost(self)
+    }
+
+    fn status(&self) -> Status {
+        Game::status(self)
+    }
+
+    fn board(&self, pad: Option<u32>, buffer: Option<Guess<G, T>>) -> Vec<Guess<G, T>> {
+        Game::board(self, pad, buffer)
+    }
+}
+
+/// Check that a word can be guessed
+/// The word must have `len` atoms, be in the word list or the answers, and not have been guessed yet
+/// The word list is checked first, so the answers are only scanned for words it does not hold
+/// # Arguments
+/// * `word` - The word to guess
+/// * `len` - The number of atoms in the correct word
+/// * `word_list` - The dictionary of words that can be guessed
+/// * `answers` - The words that can be the correct word, always allowed as guesses
+/// * `is_guessed` - Whether a word has been guessed already
+/// # Returns
+/// The word, or the first rule it breaks
+pub(crate) fn check_guess<
+    T: PartialEq + Clone + Debug,
+    G: Guessable<T> + Debug,
+    D: Dictionary<G>,
+>(
+    word: G,
+    len: usize,
+    word_list: &D,
+    answers: Option<&[G]>,
+    is_guessed: impl Fn(&G) -> bool,
+) -> Result<G, error::WordleError<G, T>> {
+    if word.atom_count() != len {
+        return Err(error::WordleError::WordLengthMismatch(word));
+    }
+
+    if !word_list.contains_word(&word) && !answers.is_some_and(|a| a.contains(&word)) {
+        return Err(error::WordleError::InvalidWord(word));
+    }
+
+    if is_guessed(&word) {
+        return Err(error::WordleError::WordAlreadyGuessed(word));
+    }
+
+    Ok(word)
+}
+
+/// Build the rows of a board from its guesses
+/// # Arguments
+/// * `guesses` - The guesses on the board
+/// * `len` - The number of atoms in a row
+/// * `max_tries` - The maximum number of rows
+/// * `pad` - The number of empty guesses to pad the board with
+/// * `buffer` - A buffer guess to add to the board
+pub(crate) fn board_rows<T: PartialEq + Clone, G: PartialEq + Clone + Default>(
+    guesses: &[Guess<G, T>],
+    len: usize,
+    max_tries: u8,
+    pad: Option<u32>,
+    buffer: Option<Guess<G, T>>,
+) -> Vec<Guess<G, T>> {
+    let mut guesses = guesses.to_vec();
+
+    if let Some(mut guess) = buffer {
+        // pad the buffer guess with empty guesses
+        let n = len.max(guess.guess.len());
+        guess.guess.resize(n, GuessResult::Empty);
+        guesses.push(guess);
+    }
+
+    if let Some(pad) = pad {
+        let num_cell = (max_tries as usize).saturating_sub(guesses.len());
+        let n = (pad as usize).min(num_cell);
+
+        for _ in 0..n {
+            guesses.push(Guess {
+                word: G::default(),
+                guess: vec![GuessResult::Empty; len],
+            });
         }
+    }
+
+    guesses
+}
+
+/// A view of a game without the correct word or the word list
+/// It only carries what a player can already see on their board
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HiddenGame<T: PartialEq + Clone, G: PartialEq + Clone> {
+    pub max_tries: u8,
+    pub guesses: Vec<Guess<G, T>>,
+    pub hard_mode: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub columns: Vec<guesser::Column>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status: Status,
+}
 
-        guesses
+impl<T: PartialEq + Clone, G: PartialEq + Clone> HiddenGame<T, G> {
+    /// Check if the game has been won
+    pub fn won(&self) -> bool {
+        self.status == Status::Won
     }
 
-    /// End the game
-    /// Clear the guesses and set the maximum number of tries to 0
-    pub fn end_game(&mut self) {
-        self.guesses.clear();
-        self.max_tries = 0;
+    /// Check if the game is over, won, lost or abandoned
+    pub fn game_over(&self) -> bool {
+        self.status != Status::InProgress
     }
 }
 
@@ -153,6 +560,11 @@ mod tests {
     use guesser::GuessResult;
     use std::error::Error;
 
+    /// Build a word list from string literals
+    pub(crate) fn words(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
     #[test]
     fn test_guess() -> Result<(), Box<dyn Error>> {
         let word_list = vec!["hello".to_string(), "world".to_string()];
@@ -274,4 +686,300 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_guess_length_mismatch() -> Result<(), Box<dyn Error>> {
+        let word_list = vec!["hello".to_string(), "hell".to_string(), "héllo".to_string()];
+        let word = "hello".to_string();
+        let mut game = Game::new(5, word, word_list);
+
+        let res = game.guess("hell".to_string());
+        assert_eq!(
+            res,
+            Err(WordleError::WordLengthMismatch("hell".to_string()))
+        );
+
+        // five chars even though it is six bytes
+        let res = game.guess("héllo".to_string())?;
+        assert_eq!(res.guess.len(), 5);
+
+        let board = game.board(Some(1), None);
+        assert_eq!(board[1].guess, vec![GuessResult::Empty; 5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remaining_candidates() -> Result<(), Box<dyn Error>> {
+        let word_list = vec![
+            "hello".to_string(),
+            "world".to_string(),
+            "jello".to_string(),
+            "hillo".to_string(),
+            "lolly".to_string(),
+        ];
+        let word = "hello".to_string();
+        let mut game = Game::new(5, word, word_list);
+        assert_eq!(game.remaining_count(), 5);
+
+        game.guess("world".to_string())?;
+        assert_eq!(
+            game.remaining_candidates(),
+            vec![
+                &"hello".to_string(),
+                &"jello".to_string(),
+                &"hillo".to_string()
+            ]
+        );
+
+        assert!(game.is_candidate(&"jello".to_string()));
+        assert!(!game.is_candidate(&"lolly".to_string()));
+
+        game.guess("hillo".to_string())?;
+        assert_eq!(game.remaining_candidates(), vec![&"hello".to_string()]);
+        assert_eq!(game.remaining_count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hard_mode() -> Result<(), Box<dyn Error>> {
+        let word_list = vec![
+            "hello".to_string(),
+            "world".to_string(),
+            "lords".to_string(),
+            "cells".to_string(),
+            "pools".to_string(),
+        ];
+        let word = "hello".to_string();
+        let mut game = Game::new(6, word, word_list).with_hard_mode(true);
+        assert!(game.is_hard_mode());
+
+        // `o` is misplaced and `l` is correct
+        game.guess("world".to_string())?;
+
+        let res = game.guess("lords".to_string());
+        assert_eq!(
+            res,
+            Err(WordleError::HardModeCorrect {
+                atom: 'l',
+                position: 3
+            })
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Hard mode: position 4 must be `'l'`"
+        );
+
+        let res = game.guess("cells".to_string());
+        assert_eq!(res, Err(WordleError::HardModeMisplaced('o')));
+
+        game.guess("pools".to_string())?;
+        assert!(!game.game_over());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_history() -> Result<(), Box<dyn Error>> {
+        let word_list = vec![
+            "hello".to_string(),
+            "world".to_string(),
+            "lords".to_string(),
+            "pools".to_string(),
+        ];
+        let mut game = Game::new(4, "hello".to_string(), word_list.clone());
+        assert_eq!(game.max_tries(), 4);
+        assert_eq!(game.remaining_tries(), 4);
+
+        game.guess("world".to_string())?;
+        game.guess("lords".to_string())?;
+        assert_eq!(game.remaining_tries(), 2);
+        assert_eq!(game.guesses().len(), 2);
+        assert_eq!(game.guesses()[1].word, "lords");
+        assert_eq!(game.word_list(), &word_list);
+
+        let history = game.guesses().iter().map(|g| g.word.clone());
+        let restored = Game::from_history(4, "hello".to_string(), word_list.clone(), history)?;
+        assert_eq!(restored, game);
+
+        // every guess of the history is validated again
+        let history = ["world", "lords"].map(String::from);
+        let restored = Game::new(4, "hello".to_string(), word_list.clone())
+            .with_hard_mode(true)
+            .with_history(history);
+        assert_eq!(
+            restored,
+            Err(WordleError::HardModeCorrect {
+                atom: 'l',
+                position: 3
+            })
+        );
+
+        let history = ["world", "world"].map(String::from);
+        let restored = Game::from_history(4, "hello".to_string(), word_list.clone(), history);
+        assert_eq!(
+            restored,
+            Err(WordleError::WordAlreadyGuessed("world".to_string()))
+        );
+
+        let history = ["hello", "world"].map(String::from);
+        let restored = Game::from_history(4, "hello".to_string(), word_list, history);
+        assert_eq!(restored, Err(WordleError::GameWon));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_status() -> Result<(), Box<dyn Error>> {
+        let word_list = vec![
+            "hello".to_string(),
+            "world".to_string(),
+            "jello".to_string(),
+        ];
+        let mut game = Game::new(2, "hello".to_string(), word_list.clone());
+        assert_eq!(game.status(), Status::InProgress);
+
+        game.guess("world".to_string())?;
+        assert_eq!(game.end_game(), "hello");
+        assert_eq!(game.status(), Status::Abandoned);
+        assert!(game.abandoned() && game.game_over());
+        assert!(!game.won() && !game.lost());
+
+        // the history survives giving up
+        assert_eq!(game.guesses().len(), 1);
+        assert_eq!(game.remaining_tries(), 1);
+        assert_eq!(game.share(), "X/2\n\n⬛🟨⬛🟩⬛");
+        assert_eq!(
+            game.guess("hello".to_string()),
+            Err(WordleError::GameAbandoned)
+        );
+
+        let mut game = Game::new(2, "hello".to_string(), word_list.clone());
+        game.guess("hello".to_string())?;
+        assert_eq!(game.guess("world".to_string()), Err(WordleError::GameWon));
+
+        // giving up a finished game keeps its status
+        game.end_game();
+        assert_eq!(game.status(), Status::Won);
+
+        let mut game = Game::new(2, "hello".to_string(), word_list);
+        game.guess("world".to_string())?;
+        game.guess("jello".to_string())?;
+        game.end_game();
+        assert_eq!(game.status(), Status::Lost);
+        assert_eq!(Playable::status(&game), Status::Lost);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hide_answer() -> Result<(), Box<dyn Error>> {
+        let word_list = vec!["hello".to_string(), "world".to_string()];
+        let mut game = Game::new(5, "hello".to_string(), word_list);
+        game.guess("world".to_string())?;
+
+        let hidden = game.hide_answer();
+        assert_eq!(hidden.max_tries, 5);
+        assert_eq!(hidden.guesses, game.board(None, None));
+        assert_eq!(hidden.columns, guesser::Column::letters(5));
+        assert_eq!(hidden.status, Status::InProgress);
+        assert!(!hidden.won() && !hidden.game_over());
+
+        game.guess("hello".to_string())?;
+        let hidden = game.hide_answer();
+        assert_eq!(hidden.status, Status::Won);
+        assert!(hidden.won() && hidden.game_over());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<(), Box<dyn Error>> {
+        let word_list = vec!["hello".to_string(), "world".to_string()];
+        let mut game = Game::new(5, "hello".to_string(), word_list).with_hard_mode(true);
+        game.guess("world".to_string())?;
+
+        let json = serde_json::to_string(&game)?;
+        let restored: Game<char, String> = serde_json::from_str(&json)?;
+        assert_eq!(restored, game);
+        assert_eq!(restored.correct_word(), "hello");
+
+        let json = serde_json::to_string(&game.hide_answer())?;
+        assert!(!json.contains("hello"));
+        let hidden: HiddenGame<char, String> = serde_json::from_str(&json)?;
+        assert_eq!(hidden, game.hide_answer());
+
+        let err: WordleError<String, char> = WordleError::HardModeMisplaced('o');
+        let json = serde_json::to_string(&err)?;
+        assert_eq!(
+            serde_json::from_str::<WordleError<String, char>>(&json)?,
+            err
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_answers() -> Result<(), Box<dyn Error>> {
+        let word_list = vec![
+            "world".to_string(),
+            "hjcwa".to_string(),
+            "zzzzz".to_string(),
+        ];
+        let answers = vec![
+            "hello".to_string(),
+            "jello".to_string(),
+            "cello".to_string(),
+        ];
+        let mut game = Game::new(6, "cello".to_string(), word_list).with_answers(answers.clone());
+        assert!(game.answers().eq(answers.iter()));
+        assert_eq!(game.remaining_count(), 3);
+        assert_eq!(solver::suggest(&game), Some(&"hjcwa".to_string()));
+
+        game.guess("world".to_string())?;
+        assert_eq!(game.remaining_count(), 3);
+
+        // answers can always be guessed
+        game.guess("hello".to_string())?;
+        assert_eq!(
+            game.remaining_candidates(),
+            vec![&"jello".to_string(), &"cello".to_string()]
+        );
+        // answers missing from the word list can still be suggested, ties go to the smallest word
+        assert_eq!(solver::suggest(&game), Some(&"cello".to_string()));
+
+        let res = game.guess("hella".to_string());
+        assert_eq!(res, Err(WordleError::InvalidWord("hella".to_string())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dictionary() -> Result<(), Box<dyn Error>> {
+        let word_list: std::collections::HashSet<String> = ["hello", "world", "jello"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let mut game = Game::new(5, "hello".to_string(), word_list);
+
+        let res = game.guess("hella".to_string());
+        assert_eq!(res, Err(WordleError::InvalidWord("hella".to_string())));
+
+        game.guess("world".to_string())?;
+        assert_eq!(game.remaining_count(), 2);
+
+        // hello and jello tie, whatever order the set iterates in
+        for _ in 0..10 {
+            let word_list: std::collections::HashSet<String> =
+                game.word_list().iter().cloned().collect();
+            let mut game = Game::new(5, "hello".to_string(), word_list);
+            game.guess("world".to_string())?;
+            assert_eq!(solver::suggest(&game), Some(&"hello".to_string()));
+            assert_eq!(solver::suggest_minimax(&game), Some(&"hello".to_string()));
+        }
+
+        Ok(())
+    }
 }
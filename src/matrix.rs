@@ -0,0 +1,579 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::guesser::{Guess, Guessable};
+use crate::pattern::{Mark, Pattern};
+
+const MAGIC: &[u8; 8] = b"RWDLPM03";
+
+/// The distinct patterns of some rows, and the cells of those rows as indices into them
+type Interned = (Vec<Pattern>, Cells);
+
+/// The `PatternMatrix` struct caches the pattern of every guess against every answer
+/// Distinct patterns are stored once and every cell holds an index into them,
+/// in the narrowest integer that fits. A 5 letter word list has at most 243
+/// patterns, so it takes 1 byte per guess and answer pair
+///
+/// # Example
+///
+/// ```
+/// use rowdle::matrix::PatternMatrix;
+/// use rowdle::Guessable;
+///
+/// let words = vec!["hello".to_string(), "world".to_string()];
+/// let matrix = PatternMatrix::new(words.clone(), words.clone()).unwrap();
+///
+/// assert_eq!(Some(matrix.pattern(1, 0)), words[1].pattern(&words[0]));
+/// assert_eq!(
+///     matrix.pattern_of(&words[0], &words[1]),
+///     words[0].pattern(&words[1])
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternMatrix<G: Hash + Eq> {
+    guesses: Vec<G>,
+    answers: Vec<G>,
+    guess_index: HashMap<G, usize>,
+    answer_index: HashMap<G, usize>,
+    patterns: Vec<Pattern>,
+    cells: Cells,
+}
+
+impl<G: Hash + Eq + Clone> PatternMatrix<G> {
+    /// Score every guess against every answer
+    /// # Arguments
+    /// * `guesses` - The words that can be guessed
+    /// * `answers` - The words that can be the correct word
+    /// # Returns
+    /// The matrix, or `None` if the words are longer than `Pattern::MAX_LEN`
+    pub fn new<T: PartialEq + Clone>(guesses: Vec<G>, answers: Vec<G>) -> Option<Self>
+    where
+        G: Guessable<T>,
+    {
+        let (patterns, cells) = intern_rows(&guesses, &answers)?;
+        Some(Self::from_parts(guesses, answers, patterns, cells))
+    }
+
+    /// Score every guess against every answer, splitting the guesses over several threads
+    /// # Arguments
+    /// * `guesses` - The words that can be guessed
+    /// * `answers` - The words that can be the correct word
+    /// * `threads` - The number of threads to use, at least one is always used
+    /// # Returns
+    /// The matrix, or `None` if the words are longer than `Pattern::MAX_LEN`
+    pub fn new_parallel<T: PartialEq + Clone>(
+        guesses: Vec<G>,
+        answers: Vec<G>,
+        threads: usize,
+    ) -> Option<Self>
+    where
+        G: Guessable<T> + Sync,
+    {
+        let chunk = guesses.len().div_ceil(threads.max(1)).max(1);
+
+        let chunks: Vec<Option<Interned>> = std::thread::scope(|s| {
+            let handles: Vec<_> = guesses
+                .chunks(chunk)
+                .map(|chunk| {
+                    let answers = &answers;
+                    s.spawn(move || intern_rows(chunk, answers))
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|h| h.join().expect("pattern matrix thread panicked"))
+                .collect()
+        });
+
+        // merge the patterns of every chunk, renumbering their cells
+        let mut patterns = vec![];
+        let mut interned = HashMap::new();
+        let mut cells = Cells::with_capacity(guesses.len() * answers.len());
+        for (chunk_patterns, chunk_cells) in chunks.into_iter().collect::<Option<Vec<_>>>()? {
+            let ids: Vec<u32> = chunk_patterns
+                .into_iter()
+                .map(|pattern| intern(&mut patterns, &mut interned, pattern))
+                .collect();
+            for cell in chunk_cells.iter() {
+                cells.push(ids[cell]);
+            }
+        }
+
+        Some(Self::from_parts(guesses, answers, patterns, cells))
+    }
+
+    fn from_parts(guesses: Vec<G>, answers: Vec<G>, patterns: Vec<Pattern>, cells: Cells) -> Self {
+        Self {
+            guess_index: index(&guesses),
+            answer_index: index(&answers),
+            guesses,
+            answers,
+            patterns,
+            cells,
+        }
+    }
+
+    /// Get the words that can be guessed
+    pub fn guesses(&self) -> &[G] {
+        &self.guesses
+    }
+
+    /// Get the words that can be the correct word
+    pub fn answers(&self) -> &[G] {
+        &self.answers
+    }
+
+    /// Get the row of a guess
+    pub fn guess_index(&self, guess: &G) -> Option<usize> {
+        self.guess_index.get(guess).copied()
+    }
+
+    /// Get the column of an answer
+    pub fn answer_index(&self, answer: &G) -> Option<usize> {
+        self.answer_index.get(answer).copied()
+    }
+
+    /// Get the pattern of a guess against an answer by their indices
+    /// # Panics
+    /// Panics if either index is out of bounds
+    pub fn pattern(&self, guess: usize, answer: usize) -> Pattern {
+        assert!(answer < self.answers.len(), "answer index out of bounds");
+        self.patterns[self.cells.get(guess * self.answers.len() + answer)]
+    }
+
+    /// Get the pattern of a guess against an answer
+    /// Returns `None` if either word is not in the matrix
+    pub fn pattern_of(&self, guess: &G, answer: &G) -> Option<Pattern> {
+        Some(self.pattern(self.guess_index(guess)?, self.answer_index(answer)?))
+    }
+
+    /// Count the answers producing each pattern for a guess
+    /// # Arguments
+    /// * `guess` - The row of the guess
+    /// * `answers` - The columns of the answers to count
+    pub fn bucket_sizes(&self, guess: usize, answers: &[usize]) -> Vec<usize> {
+        let row = guess * self.answers.len();
+        let mut sizes = vec![0; self.patterns.len()];
+        for &answer in answers {
+            assert!(answer < self.answers.len(), "answer index out of bounds");
+            sizes[self.cells.get(row + answer)] += 1;
+        }
+        sizes.retain(|&size| size > 0);
+        sizes
+    }
+
+    /// Get the answers that are consistent with every guess made so far
+    /// Guesses that are not in the matrix are scored directly
+    /// # Arguments
+    /// * `guesses` - The guesses made so far
+    pub fn remaining<T: PartialEq + Clone>(&self, guesses: &[Guess<G, T>]) -> Vec<&G>
+    where
+        G: Guessable<T>,
+    {
+        let mut remaining: Vec<usize> = (0..self.answers.len()).collect();
+
+        for guess in guesses {
+            // a guess too long for a pattern cannot have been scored against these answers
+            let Some(pattern) = Pattern::from_guess(guess) else {
+                return vec![];
+            };
+            match self.guess_index(&guess.word) {
+                Some(row) => remaining.retain(|&a| self.pattern(row, a) == pattern),
+                None => {
+                    remaining.retain(|&a| guess.word.pattern(&self.answers[a]) == Some(pattern))
+                }
+            }
+        }
+
+        remaining.into_iter().map(|a| &self.answers[a]).collect()
+    }
+
+    /// Write the patterns to a binary file
+    /// The words are not stored and must be supplied again when loading,
+    /// only a fingerprint of each word list is kept to check them
+    /// # Arguments
+    /// * `path` - The file to write
+    pub fn save_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.save(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Write the patterns in binary form
+    /// # Arguments
+    /// * `writer` - The writer to write to
+    pub fn save(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&Mark::RADIX.to_le_bytes())?;
+        writer.write_all(&(self.guesses.len() as u64).to_le_bytes())?;
+        writer.write_all(&(self.answers.len() as u64).to_le_bytes())?;
+        writer.write_all(&fingerprint(&self.guesses).to_le_bytes())?;
+        writer.write_all(&fingerprint(&self.answers).to_le_bytes())?;
+        writer.write_all(&(self.patterns.len() as u64).to_le_bytes())?;
+
+        for pattern in &self.patterns {
+            writer.write_all(&pattern.code().to_le_bytes())?;
+            writer.write_all(&[pattern.len() as u8])?;
+        }
+
+        let width = Cells::width(self.patterns.len());
+        for cell in self.cells.iter() {
+            writer.write_all(&(cell as u32).to_le_bytes()[..width])?;
+        }
+
+        Ok(())
+    }
+
+    /// Read patterns written by `save_file`
+    /// # Arguments
+    /// * `path` - The file to read
+    /// * `guesses` - The words that can be guessed, in the order they were saved
+    /// * `answers` - The words that can be the correct word, in the order they were saved
+    pub fn load_file(path: impl AsRef<Path>, guesses: Vec<G>, answers: Vec<G>) -> io::Result<Self> {
+        Self::load(BufReader::new(File::open(path)?), guesses, answers)
+    }
+
+    /// Read patterns written by `save`
+    /// # Arguments
+    /// * `reader` - The reader to read from
+    /// * `guesses` - The words that can be guessed, in the order they were saved
+    /// * `answers` - The words that can be the correct word, in the order they were saved
+    pub fn load(mut reader: impl Read, guesses: Vec<G>, answers: Vec<G>) -> io::Result<Self> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("not a pattern matrix file"));
+        }
+
+        if read_u64(&mut reader)? != Mark::RADIX {
+            return Err(invalid_data(
+                "pattern matrix uses a different pattern encoding",
+            ));
+        }
+
+        if read_u64(&mut reader)? != guesses.len() as u64
+            || read_u64(&mut reader)? != answers.len() as u64
+            || read_u64(&mut reader)? != fingerprint(&guesses)
+            || read_u64(&mut reader)? != fingerprint(&answers)
+        {
+            return Err(invalid_data("pattern matrix does not match the word lists"));
+        }
+
+        let size = guesses
+            .len()
+            .checked_mul(answers.len())
+            .ok_or_else(|| invalid_data("pattern matrix is too large"))?;
+
+        // there cannot be more distinct patterns than cells, which also bounds the allocation
+        let count = read_u64(&mut reader)?;
+        if count > size as u64 || count > u64::from(u32::MAX) {
+            return Err(invalid_data("invalid pattern count"));
+        }
+
+        let mut patterns = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let code = read_u64(&mut reader)?;
+            let mut len = [0; 1];
+            reader.read_exact(&mut len)?;
+            patterns.push(
+                Pattern::from_code(code, len[0] as usize)
+                    .ok_or_else(|| invalid_data("invalid pattern"))?,
+            );
+        }
+
+        let width = Cells::width(patterns.len());
+        let mut cells = Cells::with_capacity(size);
+        for _ in 0..size {
+            let mut cell = [0; 4];
+            reader.read_exact(&mut cell[..width])?;
+            let cell = u32::from_le_bytes(cell);
+            if cell as usize >= patterns.len() {
+                return Err(invalid_data("invalid pattern index"));
+            }
+            cells.push(cell);
+        }
+
+        Ok(Self::from_parts(guesses, answers, patterns, cells))
+    }
+}
+
+/// Score some guesses against every answer, interning each pattern as it is produced
+/// Returns `None` if the words are longer than `Pattern::MAX_LEN`
+fn intern_rows<T: PartialEq + Clone, G: Guessable<T>>(
+    guesses: &[G],
+    answers: &[G],
+) -> Option<Interned> {
+    let mut patterns = vec![];
+    let mut interned = HashMap::new();
+    let mut cells = Cells::with_capacity(guesses.len() * answers.len());
+
+    for guess in guesses {
+        for answer in answers {
+            cells.push(intern(&mut patterns, &mut interned, guess.pattern(answer)?));
+        }
+    }
+
+    Some((patterns, cells))
+}
+
+/// Get the index of a pattern, adding it to the distinct patterns if it is new
+fn intern(
+    patterns: &mut Vec<Pattern>,
+    interned: &mut HashMap<Pattern, u32>,
+    pattern: Pattern,
+) -> u32 {
+    *interned.entry(pattern).or_insert_with(|| {
+        patterns.push(pattern);
+        (patterns.len() - 1) as u32
+    })
+}
+
+/// Hash a word list with FNV-1a, which unlike the standard hasher is stable between builds
+/// Integers are written little-endian and `usize` as a `u64`, so every platform
+/// gets the same fingerprint for word types that hash through them
+fn fingerprint<G: Hash>(words: &[G]) -> u64 {
+    struct Fnv1a(u64);
+
+    impl Hasher for Fnv1a {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+                self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+
+        fn write_u16(&mut self, i: u16) {
+            self.write(&i.to_le_bytes());
+        }
+
+        fn write_u32(&mut self, i: u32) {
+            self.write(&i.to_le_bytes());
+        }
+
+        fn write_u64(&mut self, i: u64) {
+            self.write(&i.to_le_bytes());
+        }
+
+        fn write_u128(&mut self, i: u128) {
+            self.write(&i.to_le_bytes());
+        }
+
+        // slice lengths and enum discriminants are hashed as usize or isize
+        fn write_usize(&mut self, i: usize) {
+            self.write_u64(i as u64);
+        }
+    }
+
+    let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+    words.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The `Cells` enum holds the pattern index of every cell in the narrowest
+/// integer that fits, widening as larger indices are pushed
+#[derive(Debug, Clone, PartialEq)]
+enum Cells {
+    U8(Vec<u8>),
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+impl Cells {
+    fn with_capacity(capacity: usize) -> Self {
+        Cells::U8(Vec::with_capacity(capacity))
+    }
+
+    /// The number of bytes a cell takes in a file with `count` patterns
+    fn width(count: usize) -> usize {
+        if count <= 1 << 8 {
+            1
+        } else if count <= 1 << 16 {
+            2
+        } else {
+            4
+        }
+    }
+
+    fn push(&mut self, index: u32) {
+        match self {
+            Cells::U8(cells) => match u8::try_from(index) {
+                Ok(index) => cells.push(index),
+                Err(_) => {
+                    *self = Cells::U16(widen(cells, cells.capacity()));
+                    self.push(index);
+                }
+            },
+            Cells::U16(cells) => match u16::try_from(index) {
+                Ok(index) => cells.push(index),
+                Err(_) => {
+                    *self = Cells::U32(widen(cells, cells.capacity()));
+                    self.push(index);
+                }
+            },
+            Cells::U32(cells) => cells.push(index),
+        }
+    }
+
+    fn get(&self, i: usize) -> usize {
+        match self {
+            Cells::U8(cells) => usize::from(cells[i]),
+            Cells::U16(cells) => usize::from(cells[i]),
+            Cells::U32(cells) => cells[i] as usize,
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Cells::U8(cells) => cells.len(),
+            Cells::U16(cells) => cells.len(),
+            Cells::U32(cells) => cells.len(),
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).map(|i| self.get(i))
+    }
+}
+
+/// Copy cells into a wider integer, keeping the capacity
+fn widen<A: Copy, B: From<A>>(cells: &[A], capacity: usize) -> Vec<B> {
+    let mut wide = Vec::with_capacity(capacity);
+    wide.extend(cells.iter().map(|&cell| B::from(cell)));
+    wide
+}
+
+fn index<G: Hash + Eq + Clone>(words: &[G]) -> HashMap<G, usize> {
+    let mut index = HashMap::with_capacity(words.len());
+    for (i, word) in words.iter().enumerate() {
+        index.entry(word.clone()).or_insert(i);
+    }
+    index
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::words;
+
+    #[test]
+    fn test_matrix() {
+        let guesses = words(&["hello", "world", "hjcwa", "zzzzz"]);
+        let answers = words(&["hello", "jello", "cello", "world"]);
+        let matrix = PatternMatrix::new(guesses.clone(), answers.clone()).unwrap();
+
+        for (i, g) in guesses.iter().enumerate() {
+            for (j, a) in answers.iter().enumerate() {
+                assert_eq!(Some(matrix.pattern(i, j)), g.pattern(a));
+                assert_eq!(matrix.pattern_of(g, a), g.pattern(a));
+            }
+        }
+        assert_eq!(matrix.pattern_of(&"jello".to_string(), &answers[0]), None);
+        assert!(matches!(matrix.cells, Cells::U8(_)));
+
+        let parallel = PatternMatrix::new_parallel(guesses.clone(), answers.clone(), 3).unwrap();
+        assert_eq!(parallel, matrix);
+
+        let mut sizes = matrix.bucket_sizes(0, &[0, 1, 2, 3]);
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 1, 2]);
+        assert_eq!(matrix.bucket_sizes(3, &[0, 1, 2, 3]), vec![4]);
+    }
+
+    #[test]
+    fn test_matrix_remaining() {
+        let guesses = words(&["hello", "world", "hjcwa", "zzzzz"]);
+        let answers = words(&["hello", "jello", "cello", "world"]);
+        let matrix = PatternMatrix::new(guesses, answers).unwrap();
+
+        let correct = "jello".to_string();
+        let history = vec![
+            "world".to_string().guess(&correct),
+            "jumpy".to_string().guess(&correct),
+        ];
+        assert_eq!(matrix.remaining(&history[..1]).len(), 3);
+        assert_eq!(matrix.remaining(&history), vec![&correct]);
+    }
+
+    #[test]
+    fn test_matrix_save_load() -> io::Result<()> {
+        let guesses = words(&["hello", "world", "hjcwa", "zzzzz"]);
+        let answers = words(&["hello", "jello", "cello", "world"]);
+        let matrix = PatternMatrix::new(guesses.clone(), answers.clone()).unwrap();
+
+        let mut bytes = vec![];
+        matrix.save(&mut bytes)?;
+        let loaded = PatternMatrix::load(&bytes[..], guesses.clone(), answers.clone())?;
+        assert_eq!(loaded, matrix);
+
+        let res = PatternMatrix::load(&bytes[..], guesses.clone(), answers[..2].to_vec());
+        assert_eq!(res.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let res = PatternMatrix::load(&bytes[1..], guesses.clone(), answers.clone());
+        assert!(res.is_err());
+
+        // the same words in another order give other patterns
+        let mut reordered = answers.clone();
+        reordered.swap(0, 1);
+        let res = PatternMatrix::load(&bytes[..], guesses.clone(), reordered);
+        assert_eq!(res.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // a corrupted pattern count is rejected before allocating
+        let mut corrupted = bytes.clone();
+        let count = MAGIC.len() + 5 * 8;
+        corrupted[count..count + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        let res = PatternMatrix::load(&corrupted[..], guesses, answers);
+        assert_eq!(res.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_matrix_wide_cells() -> io::Result<()> {
+        // 6 letter words over three letters give more than 256 distinct patterns
+        let words: Vec<String> = (0..60)
+            .map(|n: u32| {
+                (0..6)
+                    .map(|i| ['a', 'b', 'c'][(n * 7 / 3u32.pow(i) % 3) as usize])
+                    .collect()
+            })
+            .collect();
+        let matrix = PatternMatrix::new(words.clone(), words.clone()).unwrap();
+        assert!(matrix.patterns.len() > 256);
+        assert!(matches!(matrix.cells, Cells::U16(_)));
+
+        for (i, g) in words.iter().enumerate() {
+            for (j, a) in words.iter().enumerate() {
+                assert_eq!(Some(matrix.pattern(i, j)), g.pattern(a));
+            }
+        }
+
+        let parallel = PatternMatrix::new_parallel(words.clone(), words.clone(), 4).unwrap();
+        assert_eq!(parallel, matrix);
+
+        let mut bytes = vec![];
+        matrix.save(&mut bytes)?;
+        assert_eq!(
+            PatternMatrix::load(&bytes[..], words.clone(), words)?,
+            matrix
+        );
+
+        Ok(())
+    }
+}
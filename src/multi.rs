@@ -0,0 +1,357 @@
+use std::fmt::Debug;
+
+use crate::dictionary::Dictionary;
+use crate::error::WordleError;
+use crate::guesser::{Guess, Guessable};
+use crate::{board_rows, check_guess};
+
+/// The results of one guess on each board of a `MultiGame`, `None` for boards already solved
+pub type MultiGuess<G, T> = Vec<Option<Guess<G, T>>>;
+
+/// The `Board` struct represents one target of a `MultiGame` and the guesses scored against it
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Board<T: PartialEq + Clone, G: PartialEq + Clone> {
+    correct_word: G,
+    guesses: Vec<Guess<G, T>>,
+}
+
+impl<T: PartialEq + Clone, G: Guessable<T>> Board<T, G> {
+    /// Get the correct word of the board
+    pub fn correct_word(&self) -> &G {
+        &self.correct_word
+    }
+
+    /// Get the guesses scored against the board
+    /// A solved board stops receiving guesses, so its last guess is the correct word
+    pub fn guesses(&self) -> &[Guess<G, T>] {
+        &self.guesses
+    }
+
+    /// Check if the correct word of the board has been guessed
+    pub fn solved(&self) -> bool {
+        self.guesses.iter().any(|g| g.word == self.correct_word)
+    }
+
+    /// Check if a word could still be the correct word of the board
+    pub fn is_candidate(&self, word: &G) -> bool {
+        self.guesses
+            .iter()
+            .all(|g| g.word.guess(word).guess == g.guess)
+    }
+}
+
+/// A game of Wordle with several boards played at once, like Dordle, Quordle or Octordle
+/// Every guess is scored against each unsolved board, and all boards share one guess budget
+///
+/// # Example
+///
+/// ```
+/// use rowdle::multi::MultiGame;
+///
+/// let word_list = vec!["hello".to_string(), "world".to_string(), "jello".to_string()];
+/// let targets = vec!["hello".to_string(), "world".to_string()];
+/// let mut game = MultiGame::new(7, targets, word_list).unwrap();
+///
+/// let res = game.guess("hello".to_string()).unwrap();
+/// assert!(res[0].is_some() && res[1].is_some());
+/// assert!(game.boards()[0].solved());
+///
+/// // the solved board is frozen
+/// let res = game.guess("world".to_string()).unwrap();
+/// assert!(res[0].is_none());
+/// assert!(game.won());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiGame<
+    T: PartialEq + Clone + Debug,
+    G: Guessable<T> + Default + Debug,
+    D: Dictionary<G> = Vec<G>,
+> {
+    max_tries: u8,
+    boards: Vec<Board<T, G>>,
+    word_list: D,
+    answers: Option<Vec<G>>,
+    guesses: Vec<G>,
+}
+
+impl<T: PartialEq + Clone + Debug, G: Guessable<T> + Default + Debug, D: Dictionary<G>>
+    MultiGame<T, G, D>
+{
+    /// Create a new game with one board per correct word
+    /// # Arguments
+    /// * `max_tries` - The maximum number of tries allowed, shared by every board
+    /// * `correct_words` - The correct word of each board, all of the same length
+    /// * `word_list` - A dictionary of words that can be guessed, also used as the list of possible answers
+    /// # Returns
+    /// The game, `NoAnswers` if there are no correct words, or `WordLengthMismatch`
+    /// with the first correct word that is not as long as the others
+    pub fn new(
+        max_tries: u8,
+        correct_words: Vec<G>,
+        word_list: D,
+    ) -> Result<Self, WordleError<G, T>> {
+        let Some(len) = correct_words.first().map(|w| w.atom_count()) else {
+            return Err(WordleError::NoAnswers);
+        };
+        if let Some(word) = correct_words.iter().find(|w| w.atom_count() != len) {
+            return Err(WordleError::WordLengthMismatch(word.clone()));
+        }
+
+        Ok(Self {
+            max_tries,
+            boards: correct_words
+                .into_iter()
+                .map(|correct_word| Board {
+                    correct_word,
+                    guesses: vec![],
+                })
+                .collect(),
+            word_list,
+            answers: None,
+            guesses: vec![],
+        })
+    }
+
+    /// Set the list of words that can be the correct word
+    /// Answers are always allowed as guesses
+    pub fn with_answers(mut self, answers: Vec<G>) -> Self {
+        self.answers = Some(answers);
+        self
+    }
+
+    /// Get the words that can be the correct word
+    pub fn answers(&self) -> Box<dyn Iterator<Item = &G> + '_> {
+        match &self.answers {
+            Some(answers) => Box::new(answers.iter()),
+            None => self.word_list.words(),
+        }
+    }
+
+    /// Make a guess against every unsolved board
+    /// # Arguments
+    /// * `word` - The word to guess
+    /// # Returns
+    /// The result of each board, `None` for boards that were already solved
+    pub fn guess(&mut self, word: G) -> Result<MultiGuess<G, T>, WordleError<G, T>> {
+        if self.won() {
+            return Err(WordleError::GameWon);
+        }
+        if self.game_over() {
+            return Err(WordleError::MaxTriesExceeded);
+        }
+
+        let word = check_guess(
+            word,
+            self.word_len(),
+            &self.word_list,
+            self.answers.as_deref(),
+            |w| self.is_word_guessed(w),
+        )?;
+
+        let results = self
+            .boards
+            .iter_mut()
+            .map(|board| {
+                if board.solved() {
+                    return None;
+                }
+
+                let res = word.guess(&board.correct_word);
+                board.guesses.push(res.clone());
+                Some(res)
+            })
+            .collect();
+
+        self.guesses.push(word);
+        Ok(results)
+    }
+
+    /// Get the number of atoms in the correct words
+    fn word_len(&self) -> usize {
+        self.boards
+            .first()
+            .map_or(0, |b| b.correct_word.atom_count())
+    }
+
+    /// Get the boards
+    pub fn boards(&self) -> &[Board<T, G>] {
+        &self.boards
+    }
+
+    /// Get the number of boards
+    pub fn board_count(&self) -> usize {
+        self.boards.len()
+    }
+
+    /// Get the words guessed so far, in order
+    pub fn guesses(&self) -> &[G] {
+        &self.guesses
+    }
+
+    /// Check if a word has been guessed
+    pub fn is_word_guessed(&self, word: &G) -> bool {
+        self.guesses.contains(word)
+    }
+
+    /// Get the number of boards that have been solved
+    pub fn solved_count(&self) -> usize {
+        self.boards.iter().filter(|b| b.solved()).count()
+    }
+
+    /// Get the answers that could still be the correct word of a board
+    /// Returns an empty list if there is no board at that index
+    pub fn remaining_candidates(&self, index: usize) -> Vec<&G> {
+        match self.boards.get(index) {
+            Some(board) => self.answers().filter(|w| board.is_candidate(w)).collect(),
+            None => vec![],
+        }
+    }
+
+    /// Check if the game is won
+    /// A game is won if every board has been solved
+    pub fn won(&self) -> bool {
+        self.boards.iter().all(|b| b.solved())
+    }
+
+    /// Check if the game is lost
+    /// A game is lost if the maximum number of tries has been used without solving every board
+    pub fn lost(&self) -> bool {
+        self.guesses.len() >= self.max_tries as usize && !self.won()
+    }
+
+    /// Check if the game is over
+    /// A game is over if the game is won or lost
+    pub fn game_over(&self) -> bool {
+        self.won() || self.lost()
+    }
+
+    /// get a 2d vector of one board
+    /// # Arguments
+    /// * `index` - The index of the board
+    /// * `pad` - The number of empty guesses to pad the board with
+    /// * `buffer` - A buffer guess to add to the board, ignored once the board is solved
+    /// # Returns
+    /// A 2d vector of the board, empty if there is no board at that index
+    pub fn board(
+        &self,
+        index: usize,
+        pad: Option<u32>,
+        buffer: Option<Guess<G, T>>,
+    ) -> Vec<Guess<G, T>> {
+        let Some(board) = self.boards.get(index) else {
+            return vec![];
+        };
+
+        let buffer = buffer.filter(|_| !board.solved());
+        board_rows(&board.guesses, self.word_len(), self.max_tries, pad, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::guesser::GuessResult;
+    use crate::tests::words;
+    use std::error::Error;
+
+    #[test]
+    fn test_multi_game() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["hello", "world", "jello", "cello", "wordl"]);
+        let targets = words(&["hello", "world", "cello"]);
+        let mut game = MultiGame::new(5, targets, word_list)?;
+        assert_eq!(game.board_count(), 3);
+
+        let res = game.guess("jello".to_string())?;
+        assert_eq!(res.len(), 3);
+        assert_eq!(game.solved_count(), 0);
+        assert_eq!(game.remaining_candidates(0), vec!["hello", "cello"]);
+        assert_eq!(game.remaining_candidates(1), vec!["world"]);
+
+        game.guess("hello".to_string())?;
+        assert_eq!(game.solved_count(), 1);
+
+        let res = game.guess("world".to_string())?;
+        assert!(res[0].is_none());
+        assert_eq!(
+            res[1],
+            Some("world".to_string().guess(&"world".to_string()))
+        );
+        assert_eq!(game.boards()[0].guesses().len(), 2);
+        assert_eq!(game.boards()[1].guesses().len(), 3);
+        assert!(!game.won());
+
+        assert_eq!(
+            game.guess("world".to_string()),
+            Err(WordleError::WordAlreadyGuessed("world".to_string()))
+        );
+        assert_eq!(
+            game.guess("hella".to_string()),
+            Err(WordleError::InvalidWord("hella".to_string()))
+        );
+
+        game.guess("cello".to_string())?;
+        assert!(game.won());
+        assert!(game.game_over());
+        assert_eq!(game.guess("wordl".to_string()), Err(WordleError::GameWon));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_game_lost() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["hello", "world", "jello", "cello"]);
+        let targets = words(&["hello", "world"]);
+        let mut game = MultiGame::new(2, targets, word_list)?;
+
+        game.guess("hello".to_string())?;
+        game.guess("jello".to_string())?;
+        assert!(game.lost());
+        assert!(!game.won());
+        assert_eq!(
+            game.guess("world".to_string()),
+            Err(WordleError::MaxTriesExceeded)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_game_targets() {
+        let word_list = words(&["hello", "world", "hi"]);
+
+        let res = MultiGame::<char, String>::new(6, vec![], word_list.clone());
+        assert_eq!(res, Err(WordleError::NoAnswers));
+
+        let res = MultiGame::new(6, words(&["hello", "hi"]), word_list);
+        assert_eq!(res, Err(WordleError::WordLengthMismatch("hi".to_string())));
+    }
+
+    #[test]
+    fn test_multi_board() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["hello", "world", "jello"]);
+        let targets = words(&["hello", "world"]);
+        let mut game = MultiGame::new(6, targets, word_list)?;
+        game.guess("hello".to_string())?;
+
+        let buffer = Guess {
+            word: "wo".to_string(),
+            guess: vec![GuessResult::Custom('w'), GuessResult::Custom('o')],
+        };
+
+        // the solved board ignores the buffer
+        let board = game.board(0, Some(6), Some(buffer.clone()));
+        assert_eq!(board.len(), 6);
+        assert_eq!(board[1].guess, vec![GuessResult::Empty; 5]);
+
+        let board = game.board(1, Some(6), Some(buffer));
+        assert_eq!(board.len(), 6);
+        assert_eq!(board[1].guess.len(), 5);
+        assert_eq!(board[1].guess[0], GuessResult::Custom('w'));
+
+        assert!(game.board(2, None, None).is_empty());
+
+        Ok(())
+    }
+}
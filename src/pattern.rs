@@ -0,0 +1,301 @@
+use crate::guesser::{Guess, GuessResult, Guessable};
+
+/// The `Mark` enum represents the kind of a `GuessResult` without its atom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mark {
+    Incorrect,
+    Misplaced,
+    Correct,
+    Custom,
+    Empty,
+    Higher,
+    Lower,
+    Partial,
+}
+
+impl Mark {
+    /// The number of distinct marks, used as the base of the pattern encoding
+    pub const RADIX: u64 = 8;
+
+    fn digit(self) -> u64 {
+        match self {
+            Mark::Incorrect => 0,
+            Mark::Misplaced => 1,
+            Mark::Correct => 2,
+            Mark::Custom => 3,
+            Mark::Empty => 4,
+            Mark::Higher => 5,
+            Mark::Lower => 6,
+            Mark::Partial => 7,
+        }
+    }
+
+    fn from_digit(digit: u64) -> Self {
+        match digit {
+            0 => Mark::Incorrect,
+            1 => Mark::Misplaced,
+            2 => Mark::Correct,
+            3 => Mark::Custom,
+            5 => Mark::Higher,
+            6 => Mark::Lower,
+            7 => Mark::Partial,
+            _ => Mark::Empty,
+        }
+    }
+
+    /// Get the mark of a result
+    pub fn of<T: PartialEq>(result: &GuessResult<T>) -> Self {
+        match result {
+            GuessResult::Correct(_) => Mark::Correct,
+            GuessResult::Incorrect(_) => Mark::Incorrect,
+            GuessResult::Misplaced(_) => Mark::Misplaced,
+            GuessResult::Empty => Mark::Empty,
+            GuessResult::Custom(_) => Mark::Custom,
+            GuessResult::Higher(_) => Mark::Higher,
+            GuessResult::Lower(_) => Mark::Lower,
+            GuessResult::Partial(_) => Mark::Partial,
+        }
+    }
+
+    /// Turn the mark back into a result holding an atom
+    pub fn with_atom<T: PartialEq>(self, atom: T) -> GuessResult<T> {
+        match self {
+            Mark::Correct => GuessResult::Correct(atom),
+            Mark::Incorrect => GuessResult::Incorrect(atom),
+            Mark::Misplaced => GuessResult::Misplaced(atom),
+            Mark::Empty => GuessResult::Empty,
+            Mark::Custom => GuessResult::Custom(atom),
+            Mark::Higher => GuessResult::Higher(atom),
+            Mark::Lower => GuessResult::Lower(atom),
+            Mark::Partial => GuessResult::Partial(atom),
+        }
+    }
+}
+
+/// The `Pattern` struct represents the feedback of a guess without its atoms
+/// The marks are packed into a single integer in base `Mark::RADIX`, so
+/// patterns are cheap to copy, compare and hash
+///
+/// # Example
+///
+/// ```
+/// use rowdle::pattern::{Mark, Pattern};
+/// use rowdle::Guessable;
+///
+/// let guess = "world".to_string();
+/// let pattern = guess.pattern(&"hello".to_string()).unwrap();
+///
+/// assert_eq!(pattern.mark(1), Some(Mark::Misplaced));
+/// assert_eq!(pattern.mark(3), Some(Mark::Correct));
+/// assert_eq!(pattern.to_guess(&guess), guess.guess(&"hello".to_string()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pattern {
+    code: u64,
+    len: u8,
+}
+
+impl Pattern {
+    /// The maximum number of marks a pattern can hold
+    pub const MAX_LEN: usize = 21;
+
+    /// Create a pattern from its marks
+    /// Returns `None` if there are more than `Pattern::MAX_LEN` marks
+    pub fn from_marks(marks: impl IntoIterator<Item = Mark>) -> Option<Self> {
+        let mut code = 0;
+        let mut place = 1;
+        let mut len = 0;
+
+        for mark in marks {
+            if len == Self::MAX_LEN {
+                return None;
+            }
+            code += mark.digit() * place;
+            place = place.wrapping_mul(Mark::RADIX);
+            len += 1;
+        }
+
+        Some(Self {
+            code,
+            len: len as u8,
+        })
+    }
+
+    /// Create a pattern from the results of a guess
+    /// Returns `None` if there are more than `Pattern::MAX_LEN` results
+    pub fn from_results<T: PartialEq>(results: &[GuessResult<T>]) -> Option<Self> {
+        Self::from_marks(results.iter().map(Mark::of))
+    }
+
+    /// Create a pattern from a guess
+    /// Returns `None` if there are more than `Pattern::MAX_LEN` results
+    pub fn from_guess<G: PartialEq + Clone, T: PartialEq + Clone>(
+        guess: &Guess<G, T>,
+    ) -> Option<Self> {
+        Self::from_results(&guess.guess)
+    }
+
+    /// Create a pattern from its packed code and length
+    /// Returns `None` if the code does not fit the length
+    pub fn from_code(code: u64, len: usize) -> Option<Self> {
+        if len > Self::MAX_LEN || code >= Mark::RADIX.pow(len as u32) {
+            return None;
+        }
+
+        Some(Self {
+            code,
+            len: len as u8,
+        })
+    }
+
+    /// Create a pattern where every mark is `Correct`
+    pub fn solved(len: usize) -> Option<Self> {
+        Self::from_marks(std::iter::repeat_n(Mark::Correct, len))
+    }
+
+    /// Get the packed code of the pattern
+    pub fn code(&self) -> u64 {
+        self.code
+    }
+
+    /// Get the number of marks in the pattern
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Check if the pattern has no marks
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the mark at a position
+    pub fn mark(&self, position: usize) -> Option<Mark> {
+        if position >= self.len() {
+            return None;
+        }
+
+        let digit = self.code / Mark::RADIX.pow(position as u32) % Mark::RADIX;
+        Some(Mark::from_digit(digit))
+    }
+
+    /// Get the marks of the pattern, in order
+    pub fn marks(&self) -> impl Iterator<Item = Mark> + '_ {
+        (0..self.len()).filter_map(|i| self.mark(i))
+    }
+
+    /// Check if every mark is `Correct`
+    pub fn is_solved(&self) -> bool {
+        self.marks().all(|m| m == Mark::Correct)
+    }
+
+    /// Turn the pattern back into a guess of a word
+    /// # Arguments
+    /// * `word` - The guessed word the pattern belongs to
+    pub fn to_guess<T: PartialEq + Clone, G: Guessable<T>>(&self, word: &G) -> Guess<G, T> {
+        let mut atoms = word.atoms().into_iter();
+
+        Guess {
+            guess: self
+                .marks()
+                .map(|mark| match mark {
+                    Mark::Empty => GuessResult::Empty,
+                    mark => atoms
+                        .next()
+                        .map_or(GuessResult::Empty, |atom| mark.with_atom(atom)),
+                })
+                .collect(),
+            word: word.clone(),
+        }
+    }
+}
+
+/// The feedback of a guess as a hash key: packed into a pattern when it fits,
+/// the full list of marks for words longer than `Pattern::MAX_LEN`
+pub(crate) type FeedbackKey = Result<Pattern, Vec<Mark>>;
+
+/// Get the feedback key of guessing a word against another
+pub(crate) fn feedback_key<T: PartialEq + Clone, G: Guessable<T>>(
+    guess: &G,
+    other: &G,
+) -> FeedbackKey {
+    guess
+        .pattern(other)
+        .ok_or_else(|| guess.guess(other).guess.iter().map(Mark::of).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_pattern() {
+        let guess = "world".to_string();
+        let correct = "hello".to_string();
+        let pattern = guess.pattern(&correct).unwrap();
+
+        assert_eq!(pattern.len(), 5);
+        assert_eq!(
+            pattern.marks().collect::<Vec<_>>(),
+            vec![
+                Mark::Incorrect,
+                Mark::Misplaced,
+                Mark::Incorrect,
+                Mark::Correct,
+                Mark::Incorrect
+            ]
+        );
+        assert_eq!(Pattern::from_guess(&guess.guess(&correct)), Some(pattern));
+        assert_eq!(pattern.to_guess(&guess), guess.guess(&correct));
+        assert_eq!(Pattern::from_code(pattern.code(), 5), Some(pattern));
+        assert!(!pattern.is_solved());
+
+        let solved = correct.pattern(&correct).unwrap();
+        assert!(solved.is_solved());
+        assert_eq!(Pattern::solved(5), Some(solved));
+    }
+
+    #[test]
+    fn test_pattern_hash() {
+        let correct = "hello".to_string();
+        let patterns: HashSet<Pattern> = ["jello", "cello", "world", "hillo"]
+            .iter()
+            .filter_map(|w| w.to_string().pattern(&correct))
+            .collect();
+
+        // jello and cello give the same feedback
+        assert_eq!(patterns.len(), 3);
+    }
+
+    #[test]
+    fn test_pattern_limits() {
+        assert!(Pattern::from_marks(vec![Mark::Correct; Pattern::MAX_LEN]).is_some());
+        assert!(Pattern::from_marks(vec![Mark::Correct; Pattern::MAX_LEN + 1]).is_none());
+        assert!(Pattern::from_code(Mark::RADIX, 1).is_none());
+        assert_eq!(Pattern::from_marks(vec![]), Some(Pattern::default()));
+
+        // every mark survives a round trip
+        let marks = vec![
+            Mark::Partial,
+            Mark::Lower,
+            Mark::Higher,
+            Mark::Empty,
+            Mark::Custom,
+            Mark::Correct,
+            Mark::Misplaced,
+            Mark::Incorrect,
+        ];
+        let pattern = Pattern::from_marks(marks.clone()).unwrap();
+        assert_eq!(pattern.marks().collect::<Vec<_>>(), marks);
+
+        // longer words have no pattern but still get a feedback key
+        let long = "a".repeat(Pattern::MAX_LEN + 1);
+        assert_eq!(long.pattern(&long), None);
+        assert_eq!(
+            feedback_key(&long, &long),
+            Err(vec![Mark::Correct; Pattern::MAX_LEN + 1])
+        );
+    }
+}
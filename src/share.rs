@@ -0,0 +1,267 @@
+use std::fmt::Debug;
+
+use crate::dictionary::Dictionary;
+use crate::guesser::{Column, GuessResult, Guessable};
+use crate::Game;
+
+/// The `Palette` struct holds the glyph drawn for each kind of result
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Palette {
+    pub correct: String,
+    pub misplaced: String,
+    pub incorrect: String,
+    pub empty: String,
+    /// Drawn for `Custom` results when no custom glyph is set
+    pub custom: String,
+    pub higher: String,
+    pub lower: String,
+    pub partial: String,
+}
+
+impl Palette {
+    /// The classic palette for dark backgrounds
+    pub fn dark() -> Self {
+        Self {
+            correct: "🟩".to_string(),
+            misplaced: "🟨".to_string(),
+            incorrect: "⬛".to_string(),
+            empty: "⬛".to_string(),
+            custom: "🟪".to_string(),
+            higher: "⬆️".to_string(),
+            lower: "⬇️".to_string(),
+            partial: "🟧".to_string(),
+        }
+    }
+
+    /// The classic palette for light backgrounds
+    pub fn light() -> Self {
+        Self {
+            incorrect: "⬜".to_string(),
+            empty: "⬜".to_string(),
+            ..Self::dark()
+        }
+    }
+
+    /// The high contrast palette for dark backgrounds, friendlier to colour-blind players
+    pub fn high_contrast_dark() -> Self {
+        Self {
+            correct: "🟧".to_string(),
+            misplaced: "🟦".to_string(),
+            partial: "🟨".to_string(),
+            ..Self::dark()
+        }
+    }
+
+    /// The high contrast palette for light backgrounds, friendlier to colour-blind players
+    pub fn high_contrast_light() -> Self {
+        Self {
+            correct: "🟧".to_string(),
+            misplaced: "🟦".to_string(),
+            partial: "🟨".to_string(),
+            ..Self::light()
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// A function drawing the glyph of a `Custom` atom
+pub type CustomGlyph<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
+/// The `ShareOptions` struct configures how a game is rendered as share text
+///
+/// # Example
+///
+/// ```
+/// use rowdle::share::{Palette, ShareOptions};
+/// use rowdle::Game;
+///
+/// let word_list = vec!["hello".to_string(), "world".to_string()];
+/// let mut game = Game::new(6, "hello".to_string(), word_list);
+/// game.guess("world".to_string()).unwrap();
+/// game.guess("hello".to_string()).unwrap();
+///
+/// let options = ShareOptions::new()
+///     .with_title("Rowdle 1")
+///     .with_palette(Palette::light());
+/// assert_eq!(game.share_with(&options), "Rowdle 1 2/6\n\n⬜🟨⬜🟩⬜\n🟩🟩🟩🟩🟩");
+/// ```
+pub struct ShareOptions<'a, T> {
+    palette: Palette,
+    title: Option<String>,
+    custom: Option<CustomGlyph<'a, T>>,
+    labels: bool,
+}
+
+impl<'a, T> ShareOptions<'a, T> {
+    /// Create the default options: the dark palette and no title
+    pub fn new() -> Self {
+        Self {
+            palette: Palette::default(),
+            title: None,
+            custom: None,
+            labels: false,
+        }
+    }
+
+    /// Set the palette
+    pub fn with_palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Set a title to put in front of the score
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the glyph drawn for each `Custom` atom
+    pub fn with_custom(mut self, custom: impl Fn(&T) -> String + 'a) -> Self {
+        self.custom = Some(Box::new(custom));
+        self
+    }
+
+    /// Set whether to put a line of column labels above the rows
+    /// Only games whose words describe their columns get the line
+    pub fn with_labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Get the palette
+    pub fn palette(&self) -> &Palette {
+        &self.palette
+    }
+}
+
+impl<T> Default for ShareOptions<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Render a row of results as glyphs
+/// # Arguments
+/// * `row` - The results to render
+/// * `options` - The palette and custom glyphs to use
+pub fn render_row<T: PartialEq>(row: &[GuessResult<T>], options: &ShareOptions<T>) -> String {
+    let palette = &options.palette;
+
+    row.iter()
+        .map(|result| match result {
+            GuessResult::Correct(_) => palette.correct.clone(),
+            GuessResult::Misplaced(_) => palette.misplaced.clone(),
+            GuessResult::Incorrect(_) => palette.incorrect.clone(),
+            GuessResult::Empty => palette.empty.clone(),
+            GuessResult::Higher(_) => palette.higher.clone(),
+            GuessResult::Lower(_) => palette.lower.clone(),
+            GuessResult::Partial(_) => palette.partial.clone(),
+            GuessResult::Custom(atom) => match &options.custom {
+                Some(custom) => custom(atom),
+                None => palette.custom.clone(),
+            },
+        })
+        .collect()
+}
+
+/// Render a game as share text
+/// The header holds the number of tries used, or `X` if the game was not won,
+/// followed by `*` in hard mode
+/// # Arguments
+/// * `game` - The game to render
+/// * `options` - The palette, title and custom glyphs to use
+pub fn share<T: PartialEq + Clone + Debug, G: Guessable<T> + Default + Debug, D: Dictionary<G>>(
+    game: &Game<T, G, D>,
+    options: &ShareOptions<T>,
+) -> String {
+    let tries = if game.won() {
+        game.guesses.len().to_string()
+    } else {
+        "X".to_string()
+    };
+    let hard_mode = if game.hard_mode { "*" } else { "" };
+
+    let mut text = match &options.title {
+        Some(title) => format!("{} {}/{}{}", title, tries, game.max_tries, hard_mode),
+        None => format!("{}/{}{}", tries, game.max_tries, hard_mode),
+    };
+    text.push('\n');
+
+    let columns = game.columns();
+    if options.labels && !columns.is_empty() {
+        let labels: Vec<String> = columns.iter().map(Column::label).collect();
+        text.push('\n');
+        text.push_str(&labels.join(" | "));
+    }
+
+    for guess in &game.guesses {
+        text.push('\n');
+        text.push_str(&render_row(&guess.guess, options));
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::guesser::Guess;
+    use std::error::Error;
+
+    #[test]
+    fn test_share() -> Result<(), Box<dyn Error>> {
+        let word_list = vec![
+            "hello".to_string(),
+            "world".to_string(),
+            "hillo".to_string(),
+        ];
+        let mut game = Game::new(6, "hello".to_string(), word_list.clone());
+        game.guess("world".to_string())?;
+        game.guess("hillo".to_string())?;
+        assert_eq!(game.share(), "X/6\n\n⬛🟨⬛🟩⬛\n🟩⬛🟩🟩🟩");
+
+        game.guess("hello".to_string())?;
+        assert_eq!(game.share(), "3/6\n\n⬛🟨⬛🟩⬛\n🟩⬛🟩🟩🟩\n🟩🟩🟩🟩🟩");
+
+        let mut game = Game::new(6, "hello".to_string(), word_list).with_hard_mode(true);
+        game.guess("hello".to_string())?;
+        let options = ShareOptions::new()
+            .with_title("Rowdle")
+            .with_palette(Palette::high_contrast_dark());
+        assert_eq!(game.share_with(&options), "Rowdle 1/6*\n\n🟧🟧🟧🟧🟧");
+
+        let options = ShareOptions::new().with_labels(true);
+        assert_eq!(
+            game.share_with(&options),
+            "1/6*\n\n1 | 2 | 3 | 4 | 5\n🟩🟩🟩🟩🟩"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_row_custom() {
+        let guess: Guess<String, u32> = Guess {
+            word: "12".to_string(),
+            guess: vec![
+                GuessResult::Correct(1),
+                GuessResult::Custom(7),
+                GuessResult::Empty,
+                GuessResult::Higher(3),
+                GuessResult::Partial(4),
+            ],
+        };
+
+        let options = ShareOptions::new();
+        assert_eq!(render_row(&guess.guess, &options), "🟩🟪⬛⬆️🟧");
+
+        let options =
+            ShareOptions::new().with_custom(|n: &u32| if *n > 5 { "⬆️" } else { "⬇️" }.to_string());
+        assert_eq!(render_row(&guess.guess, &options), "🟩⬆️⬛⬆️🟧");
+    }
+}
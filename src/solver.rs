@@ -0,0 +1,580 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::dictionary::Dictionary;
+use crate::guesser::Guessable;
+use crate::matrix::PatternMatrix;
+use crate::pattern::{feedback_key, FeedbackKey, Pattern};
+use crate::Game;
+
+/// The `Ranking` struct represents how good a guess is against a set of candidates
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ranking<'a, G> {
+    pub word: &'a G,
+    /// The score of the guess, higher is better
+    pub score: f64,
+    /// Whether the guess could itself be the correct word
+    pub is_candidate: bool,
+}
+
+/// Group candidates by the feedback a guess would receive if they were the correct word
+/// # Arguments
+/// * `guess` - The word to guess
+/// * `candidates` - The words that could be the correct word
+/// # Returns
+/// The feedback patterns together with the candidates producing them, ordered by pattern,
+/// or `None` if the words are longer than `Pattern::MAX_LEN`
+pub fn partition<'a, T: PartialEq + Clone, G: Guessable<T>>(
+    guess: &G,
+    candidates: &[&'a G],
+) -> Option<Vec<(Pattern, Vec<&'a G>)>> {
+    let mut buckets: HashMap<Pattern, Vec<&'a G>> = HashMap::new();
+    for candidate in candidates {
+        buckets
+            .entry(guess.pattern(candidate)?)
+            .or_default()
+            .push(candidate);
+    }
+
+    let mut buckets: Vec<_> = buckets.into_iter().collect();
+    buckets.sort_by_key(|(pattern, _)| *pattern);
+    Some(buckets)
+}
+
+/// Count the candidates producing each feedback
+fn bucket_sizes<T: PartialEq + Clone, G: Guessable<T>>(
+    guess: &G,
+    candidates: &[&G],
+) -> HashMap<FeedbackKey, usize> {
+    let mut sizes = HashMap::new();
+    for candidate in candidates {
+        *sizes.entry(feedback_key(guess, candidate)).or_default() += 1;
+    }
+    sizes
+}
+
+/// Calculate the expected information (in bits) of the feedback a guess would receive
+/// Every candidate is assumed to be equally likely to be the correct word
+/// # Arguments
+/// * `guess` - The word to guess
+/// * `candidates` - The words that could be the correct word
+pub fn entropy<T: PartialEq + Clone, G: Guessable<T>>(guess: &G, candidates: &[&G]) -> f64 {
+    entropy_of(bucket_sizes(guess, candidates).into_values())
+}
+
+/// Calculate the entropy of a distribution of bucket sizes
+fn entropy_of(sizes: impl IntoIterator<Item = usize>) -> f64 {
+    // sum in a fixed order, so equal distributions always get the same score
+    let mut sizes: Vec<usize> = sizes.into_iter().collect();
+    sizes.sort_unstable();
+    let total = sizes.iter().sum::<usize>() as f64;
+
+    sizes
+        .into_iter()
+        .map(|size| {
+            let p = size as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Rank every allowed guess by the expected information of its feedback
+/// Guesses that could be the correct word win ties
+/// # Arguments
+/// * `allowed` - The words that can be guessed
+/// * `candidates` - The words that could be the correct word
+/// # Returns
+/// The rankings, best first
+pub fn rank_by_entropy<'a, T: PartialEq + Clone, G: Guessable<T>>(
+    allowed: &'a [G],
+    candidates: &[&G],
+) -> Vec<Ranking<'a, G>> {
+    rank(allowed, candidates, |guess| entropy(guess, candidates))
+}
+
+/// Get the allowed guess with the highest expected information
+/// # Arguments
+/// * `allowed` - The words that can be guessed
+/// * `candidates` - The words that could be the correct word
+pub fn best_guess<'a, T: PartialEq + Clone, G: Guessable<T>>(
+    allowed: &'a [G],
+    candidates: &[&G],
+) -> Option<&'a G> {
+    rank_by_entropy(allowed, candidates).first().map(|r| r.word)
+}
+
+/// Suggest the next guess for a game
+/// The guess is picked from the word list by expected information against the
+/// answers that are still candidates. Remaining ties go to the smallest word
+/// # Arguments
+/// * `game` - The game to suggest a guess for
+pub fn suggest<
+    T: PartialEq + Clone + Debug,
+    G: Guessable<T> + Default + Debug + Ord,
+    D: Dictionary<G>,
+>(
+    game: &Game<T, G, D>,
+) -> Option<&G> {
+    let candidates = game.remaining_candidates();
+    if candidates.is_empty() || game.game_over() {
+        return None;
+    }
+
+    rank(allowed(game), &candidates, |guess| {
+        entropy(guess, &candidates)
+    })
+    .first()
+    .map(|r| r.word)
+}
+
+/// Get every word a game accepts as a guess: the word list and the answers
+/// The words are sorted, so that ties do not depend on the order of the dictionary
+pub(crate) fn allowed<
+    T: PartialEq + Clone + Debug,
+    G: Guessable<T> + Default + Debug + Ord,
+    D: Dictionary<G>,
+>(
+    game: &Game<T, G, D>,
+) -> Vec<&G> {
+    let mut allowed: Vec<&G> = game.word_list.words().collect();
+    if let Some(answers) = &game.answers {
+        allowed.extend(answers.iter().filter(|a| !game.word_list.contains_word(a)));
+    }
+    allowed.sort();
+    allowed
+}
+
+/// Rank every guess of a pattern matrix by the expected information of its feedback
+/// Patterns are looked up in the matrix, candidates missing from its answers are ignored
+/// # Arguments
+/// * `matrix` - The precomputed patterns
+/// * `candidates` - The words that could be the correct word
+/// # Returns
+/// The rankings, best first
+pub fn rank_by_entropy_cached<'a, T: PartialEq + Clone, G: Guessable<T> + Hash + Eq>(
+    matrix: &'a PatternMatrix<G>,
+    candidates: &[&G],
+) -> Vec<Ranking<'a, G>> {
+    let columns: Vec<usize> = candidates
+        .iter()
+        .filter_map(|c| matrix.answer_index(c))
+        .collect();
+
+    rank(matrix.guesses(), candidates, |guess| {
+        matrix
+            .guess_index(guess)
+            .map_or(0.0, |row| entropy_of(matrix.bucket_sizes(row, &columns)))
+    })
+}
+
+/// Suggest the next guess for a game using a pattern matrix
+/// The guess is picked from the matrix guesses by expected information against
+/// the matrix answers that are still candidates
+/// # Arguments
+/// * `game` - The game to suggest a guess for
+/// * `matrix` - A pattern matrix whose answers are the game's answers
+pub fn suggest_cached<'a, T: PartialEq + Clone + Debug, G, D: Dictionary<G>>(
+    game: &Game<T, G, D>,
+    matrix: &'a PatternMatrix<G>,
+) -> Option<&'a G>
+where
+    G: Guessable<T> + Default + Debug + Hash + Eq,
+{
+    let candidates = game.remaining_candidates_cached(matrix);
+    if candidates.is_empty() || game.game_over() {
+        return None;
+    }
+
+    rank_by_entropy_cached(matrix, &candidates)
+        .first()
+        .map(|r| r.word)
+}
+
+/// Calculate the number of candidates left in the worst case after a guess
+/// # Arguments
+/// * `guess` - The word to guess
+/// * `candidates` - The words that could be the correct word
+pub fn worst_case<T: PartialEq + Clone, G: Guessable<T>>(guess: &G, candidates: &[&G]) -> usize {
+    bucket_sizes(guess, candidates)
+        .into_values()
+        .max()
+        .unwrap_or(0)
+}
+
+/// Rank every allowed guess by the number of candidates it eliminates in the worst case
+/// Guesses that could be the correct word win ties
+/// # Arguments
+/// * `allowed` - The words that can be guessed
+/// * `candidates` - The words that could be the correct word
+/// # Returns
+/// The rankings, best first
+pub fn rank_by_worst_case<'a, T: PartialEq + Clone, G: Guessable<T>>(
+    allowed: &'a [G],
+    candidates: &[&G],
+) -> Vec<Ranking<'a, G>> {
+    rank(allowed, candidates, |guess| {
+        (candidates.len() - worst_case(guess, candidates)) as f64
+    })
+}
+
+/// Get the allowed guess that leaves the fewest candidates in the worst case
+/// # Arguments
+/// * `allowed` - The words that can be guessed
+/// * `candidates` - The words that could be the correct word
+pub fn best_minimax_guess<'a, T: PartialEq + Clone, G: Guessable<T>>(
+    allowed: &'a [G],
+    candidates: &[&G],
+) -> Option<&'a G> {
+    rank_by_worst_case(allowed, candidates)
+        .first()
+        .map(|r| r.word)
+}
+
+/// Suggest the next guess for a game that leaves the fewest candidates in the worst case
+/// Remaining ties go to the smallest word
+/// # Arguments
+/// * `game` - The game to suggest a guess for
+pub fn suggest_minimax<
+    T: PartialEq + Clone + Debug,
+    G: Guessable<T> + Default + Debug + Ord,
+    D: Dictionary<G>,
+>(
+    game: &Game<T, G, D>,
+) -> Option<&G> {
+    let candidates = game.remaining_candidates();
+    if candidates.is_empty() || game.game_over() {
+        return None;
+    }
+
+    rank(allowed(game), &candidates, |guess| {
+        (candidates.len() - worst_case(guess, &candidates)) as f64
+    })
+    .first()
+    .map(|r| r.word)
+}
+
+/// The `DecisionTree` struct represents a complete strategy for a list of answers
+/// Each node holds the word to guess and, for every feedback pattern that does
+/// not solve the game, the subtree to continue with
+/// A node without branches is the answer itself
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecisionTree<G> {
+    pub guess: G,
+    /// Whether the guess could itself be the correct word at this point
+    pub is_candidate: bool,
+    pub branches: Vec<(Pattern, DecisionTree<G>)>,
+}
+
+impl<G: PartialEq + Clone> DecisionTree<G> {
+    /// Build a decision tree that picks the minimax guess at every step
+    /// # Arguments
+    /// * `allowed` - The words that can be guessed
+    /// * `answers` - The words that could be the correct word
+    /// # Returns
+    /// `None` if the allowed guesses cannot tell some answers apart, or the
+    /// words are longer than `Pattern::MAX_LEN`
+    pub fn minimax<T: PartialEq + Clone>(allowed: &[G], answers: &[G]) -> Option<Self>
+    where
+        G: Guessable<T>,
+    {
+        let candidates: Vec<&G> = answers.iter().collect();
+        Self::build(allowed, &candidates)
+    }
+
+    fn build<T: PartialEq + Clone>(allowed: &[G], candidates: &[&G]) -> Option<Self>
+    where
+        G: Guessable<T>,
+    {
+        match candidates {
+            [] => return None,
+            [answer] => {
+                return Some(Self {
+                    guess: (*answer).clone(),
+                    is_candidate: true,
+                    branches: vec![],
+                })
+            }
+            _ => {}
+        }
+
+        let guess = best_minimax_guess(allowed, candidates)?;
+        let is_candidate = candidates.contains(&guess);
+        let buckets = partition(guess, candidates)?;
+        // the guess does not split the candidates, recursing would never end
+        if buckets.len() == 1 && !is_candidate {
+            return None;
+        }
+
+        let mut branches = vec![];
+        for (pattern, bucket) in buckets {
+            if bucket == [guess] {
+                continue;
+            }
+            branches.push((pattern, Self::build(allowed, &bucket)?));
+        }
+
+        Some(Self {
+            guess: guess.clone(),
+            is_candidate,
+            branches,
+        })
+    }
+
+    /// Get the subtree to continue with after receiving some feedback
+    /// # Arguments
+    /// * `pattern` - The feedback received for this node's guess
+    pub fn next(&self, pattern: &Pattern) -> Option<&Self> {
+        self.branches
+            .iter()
+            .find(|(p, _)| p == pattern)
+            .map(|(_, tree)| tree)
+    }
+
+    /// The number of guesses needed to solve the hardest answer
+    pub fn depth(&self) -> usize {
+        1 + self
+            .branches
+            .iter()
+            .map(|(_, tree)| tree.depth())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Check if every answer is solved within a number of tries
+    pub fn solves_within(&self, max_tries: u8) -> bool {
+        self.depth() <= max_tries as usize
+    }
+
+    /// Get the guesses the tree makes to solve an answer, ending with the answer
+    /// # Arguments
+    /// * `answer` - The correct word
+    /// # Returns
+    /// `None` if the answer is not covered by the tree
+    pub fn guesses_for<T: PartialEq + Clone>(&self, answer: &G) -> Option<Vec<&G>>
+    where
+        G: Guessable<T>,
+    {
+        let mut guesses = vec![&self.guess];
+        if self.is_candidate && self.guess == *answer {
+            return Some(guesses);
+        }
+
+        let pattern = self.guess.pattern(answer)?;
+        guesses.extend(self.next(&pattern)?.guesses_for(answer)?);
+        Some(guesses)
+    }
+
+    /// Get the guesses the tree makes for every answer it covers
+    /// Each path ends with the answer it solves
+    pub fn paths(&self) -> Vec<Vec<&G>> {
+        let mut paths = vec![];
+        self.collect_paths(&mut vec![], &mut paths);
+        paths
+    }
+
+    fn collect_paths<'a>(&'a self, prefix: &mut Vec<&'a G>, paths: &mut Vec<Vec<&'a G>>) {
+        prefix.push(&self.guess);
+        if self.is_candidate {
+            paths.push(prefix.clone());
+        }
+        for (_, tree) in &self.branches {
+            tree.collect_paths(prefix, paths);
+        }
+        prefix.pop();
+    }
+}
+
+/// Score every allowed guess and sort the rankings, best first
+pub(crate) fn rank<'a, T: PartialEq + Clone, G: Guessable<T> + 'a>(
+    allowed: impl IntoIterator<Item = &'a G>,
+    candidates: &[&G],
+    score: impl Fn(&G) -> f64,
+) -> Vec<Ranking<'a, G>> {
+    let mut rankings: Vec<Ranking<'a, G>> = allowed
+        .into_iter()
+        .map(|word| Ranking {
+            word,
+            score: score(word),
+            is_candidate: candidates.contains(&word),
+        })
+        .collect();
+
+    rankings.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(Ordering::Equal)
+            .then(b.is_candidate.cmp(&a.is_candidate))
+    });
+
+    rankings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WordleError;
+    use crate::guesser::{compare, Guess};
+    use crate::tests::words;
+    use std::error::Error;
+
+    #[test]
+    fn test_entropy() {
+        let candidates = words(&["hello", "jello", "cello", "world"]);
+        let candidates: Vec<&String> = candidates.iter().collect();
+
+        // splits every candidate into its own bucket
+        let guess = "hjcwa".to_string();
+        assert!((entropy(&guess, &candidates) - 2.0).abs() < 1e-9);
+
+        // no information at all
+        let guess = "zzzzz".to_string();
+        assert_eq!(entropy(&guess, &candidates), 0.0);
+
+        let buckets = partition(&"hello".to_string(), &candidates).unwrap();
+        assert_eq!(buckets.len(), 3);
+    }
+
+    #[test]
+    fn test_best_guess() {
+        let allowed = words(&["hello", "jello", "cello", "zzzzz", "hjcwa"]);
+        let candidates = words(&["hello", "jello", "cello"]);
+        let candidates: Vec<&String> = candidates.iter().collect();
+
+        let rankings = rank_by_entropy(&allowed, &candidates);
+        assert_eq!(rankings.len(), 5);
+        assert_eq!(rankings[0].word, "hjcwa");
+        assert!(!rankings[0].is_candidate);
+        assert_eq!(rankings.last().unwrap().word, "zzzzz");
+
+        // a single candidate should be guessed directly
+        let candidates = [&allowed[1]];
+        assert_eq!(best_guess(&allowed, &candidates), Some(&allowed[1]));
+    }
+
+    #[test]
+    fn test_suggest() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["hello", "jello", "cello", "world", "hjcwa"]);
+        let mut game = Game::new(6, "cello".to_string(), word_list);
+
+        assert_eq!(suggest(&game), Some(&"hjcwa".to_string()));
+
+        game.guess("hjcwa".to_string())?;
+        assert_eq!(suggest(&game), Some(&"cello".to_string()));
+
+        game.guess("cello".to_string())?;
+        assert_eq!(suggest(&game), None);
+        assert_eq!(suggest_minimax(&game), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_suggest_long_words() {
+        // too long to be packed into a pattern
+        let word_list = vec![
+            "a".repeat(22),
+            "b".repeat(22),
+            format!("a{}", "b".repeat(21)),
+        ];
+        let game = Game::new(6, word_list[0].clone(), word_list.clone());
+
+        assert!(suggest(&game).is_some());
+        assert!(suggest_minimax(&game).is_some());
+        assert_eq!(DecisionTree::minimax(&word_list, &word_list), None);
+    }
+
+    #[test]
+    fn test_worst_case() {
+        let allowed = words(&["hello", "jello", "cello", "zzzzz", "hjcwa"]);
+        let candidates = words(&["hello", "jello", "cello"]);
+        let candidates: Vec<&String> = candidates.iter().collect();
+
+        assert_eq!(worst_case(&allowed[0], &candidates), 2);
+        assert_eq!(worst_case(&allowed[3], &candidates), 3);
+        assert_eq!(worst_case(&allowed[4], &candidates), 1);
+
+        let rankings = rank_by_worst_case(&allowed, &candidates);
+        assert_eq!(rankings[0].word, "hjcwa");
+        assert_eq!(rankings[0].score, 2.0);
+        // candidates win ties against other words
+        assert!(rankings[1].is_candidate);
+        assert_eq!(best_minimax_guess(&allowed, &candidates), Some(&allowed[4]));
+    }
+
+    #[test]
+    fn test_decision_tree() {
+        let answers = words(&["hello", "jello", "cello", "world", "mello", "yello"]);
+        let tree = DecisionTree::minimax(&answers, &answers).unwrap();
+
+        let paths = tree.paths();
+        assert_eq!(paths.len(), answers.len());
+        for answer in &answers {
+            let guesses = tree.guesses_for(answer).unwrap();
+            assert_eq!(guesses.last(), Some(&answer));
+            assert!(guesses.len() <= tree.depth());
+            assert!(paths.contains(&guesses));
+        }
+        assert!(tree.solves_within(tree.depth() as u8));
+        assert!(!tree.solves_within(1));
+
+        let pattern = tree.guess.pattern(&answers[3]).unwrap();
+        assert!(tree.next(&pattern).is_some());
+
+        // the allowed guesses cannot tell the answers apart
+        let allowed = words(&["zzzzz"]);
+        assert_eq!(DecisionTree::minimax(&allowed, &answers), None);
+    }
+
+    #[test]
+    fn test_suggest_cached() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["hello", "jello", "cello", "world", "hjcwa"]);
+        let matrix = PatternMatrix::new(word_list.clone(), word_list.clone()).unwrap();
+        let mut game = Game::new(6, "cello".to_string(), word_list);
+
+        let candidates = game.remaining_candidates();
+        let cached = rank_by_entropy_cached(&matrix, &candidates);
+        let direct = rank_by_entropy(matrix.guesses(), &candidates);
+        assert_eq!(cached, direct);
+        assert_eq!(suggest_cached(&game, &matrix), suggest(&game));
+
+        game.guess("hjcwa".to_string())?;
+        assert_eq!(
+            game.remaining_candidates_cached(&matrix),
+            vec![&"cello".to_string()]
+        );
+        assert_eq!(suggest_cached(&game, &matrix), Some(&"cello".to_string()));
+
+        Ok(())
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+    struct Year(u16);
+
+    impl Guessable<u16> for Year {
+        fn guess(&self, other: &Self) -> Guess<Year, u16> {
+            Guess {
+                word: self.clone(),
+                guess: vec![compare(self.0, &other.0)],
+            }
+        }
+    }
+
+    #[test]
+    fn test_directional() -> Result<(), WordleError<Year, u16>> {
+        let years: Vec<Year> = (2001..=2007).map(Year).collect();
+        let candidates: Vec<&Year> = years.iter().collect();
+
+        // the middle year splits the candidates into higher and lower halves
+        assert_eq!(best_guess(&years, &candidates), Some(&Year(2004)));
+
+        let mut game = Game::new(6, Year(2006), years.clone());
+        game.guess(Year(2004))?;
+        assert_eq!(game.remaining_count(), 3);
+        assert_eq!(suggest(&game), Some(&Year(2006)));
+
+        Ok(())
+    }
+}
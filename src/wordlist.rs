@@ -0,0 +1,366 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::dictionary;
+use crate::error::WordListError;
+
+/// The `Format` enum represents how the words of a word list are laid out
+/// It is non-exhaustive because some formats depend on crate features
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Format {
+    /// One word per line
+    Lines,
+    /// Comma separated values, the words are taken from one column
+    Csv {
+        /// The index of the column holding the words
+        column: usize,
+        /// Skip the first line
+        header: bool,
+    },
+    /// A JSON array of strings
+    #[cfg(feature = "json")]
+    Json,
+}
+
+/// The `Loader` struct reads word lists and normalises their words
+///
+/// # Example
+///
+/// ```
+/// use rowdle::wordlist::Loader;
+///
+/// let text = "Hello\nwörld\nHELLO\nhi\nab-cd\n";
+/// let words: Vec<String> = Loader::new()
+///     .with_lowercase(true)
+///     .with_strip_diacritics(true)
+///     .with_dedup(true)
+///     .with_length(5)
+///     .with_charset('a'..='z')
+///     .load(text.as_bytes())
+///     .unwrap();
+///
+/// assert_eq!(words, vec!["hello", "world"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loader {
+    format: Format,
+    trim: bool,
+    lowercase: bool,
+    strip_diacritics: bool,
+    dedup: bool,
+    length: Option<RangeInclusive<usize>>,
+    charset: Option<HashSet<char>>,
+}
+
+impl Loader {
+    /// Create a loader for newline-delimited lists that only trims words
+    pub fn new() -> Self {
+        Self {
+            format: Format::Lines,
+            trim: true,
+            lowercase: false,
+            strip_diacritics: false,
+            dedup: false,
+            length: None,
+            charset: None,
+        }
+    }
+
+    /// Set the format of the word list
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Set whether surrounding whitespace is removed from words
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Set whether words are lowercased
+    pub fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Set whether accents and other combining marks are removed from words
+    pub fn with_strip_diacritics(mut self, strip_diacritics: bool) -> Self {
+        self.strip_diacritics = strip_diacritics;
+        self
+    }
+
+    /// Set whether repeated words are dropped, keeping the first one
+    pub fn with_dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    /// Only keep words with exactly this many characters
+    pub fn with_length(self, length: usize) -> Self {
+        self.with_length_range(length..=length)
+    }
+
+    /// Only keep words whose number of characters is in a range
+    pub fn with_length_range(mut self, length: RangeInclusive<usize>) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Only keep words made of these characters
+    /// The check happens after lowercasing and stripping diacritics
+    pub fn with_charset(mut self, charset: impl IntoIterator<Item = char>) -> Self {
+        self.charset = Some(charset.into_iter().collect());
+        self
+    }
+
+    /// Get the format of the word list
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Normalise a single word
+    /// Returns `None` if the word is blank or filtered out by its length or characters
+    pub fn normalize(&self, word: &str) -> Option<String> {
+        let mut word = if self.trim { word.trim() } else { word }.to_string();
+        if self.lowercase {
+            word = word.to_lowercase();
+        }
+        if self.strip_diacritics {
+            word = strip_diacritics(&word);
+        }
+
+        if word.trim().is_empty() {
+            return None;
+        }
+        if let Some(length) = &self.length {
+            if !length.contains(&word.chars().count()) {
+                return None;
+            }
+        }
+        if let Some(charset) = &self.charset {
+            if !word.chars().all(|c| charset.contains(&c)) {
+                return None;
+            }
+        }
+
+        Some(word)
+    }
+
+    /// Read a word list into any collection of words
+    /// Blank lines are skipped, and lines that cannot be read as words are reported
+    /// # Arguments
+    /// * `reader` - The source of the word list
+    pub fn load<D: FromIterator<String>>(&self, reader: impl Read) -> Result<D, WordListError> {
+        let words = match self.format {
+            Format::Lines => read_lines(reader)?,
+            Format::Csv { column, header } => read_csv(reader, column, header)?,
+            #[cfg(feature = "json")]
+            Format::Json => serde_json::from_reader(reader)?,
+        };
+
+        let mut seen = HashSet::new();
+        Ok(words
+            .iter()
+            .filter_map(|word| self.normalize(word))
+            .filter(|word| !self.dedup || seen.insert(word.clone()))
+            .collect())
+    }
+
+    /// Read a word list file into any collection of words
+    /// # Arguments
+    /// * `path` - The path of the word list
+    pub fn load_file<D: FromIterator<String>>(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<D, WordListError> {
+        self.load(File::open(path)?)
+    }
+}
+
+impl Default for Loader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Remove accents and other combining marks from a word
+/// Letters without a decomposition, such as `ø` or `ß`, are kept as they are
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(rowdle::wordlist::strip_diacritics("Crème brûlée"), "Creme brulee");
+/// ```
+pub fn strip_diacritics(word: &str) -> String {
+    word.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .nfc()
+        .collect()
+}
+
+/// Split a reader into numbered lines, reporting the line of invalid UTF-8
+fn lines(reader: impl Read) -> impl Iterator<Item = (usize, Result<String, WordListError>)> {
+    dictionary::lines(BufReader::new(reader)).map(|(number, line)| {
+        let line = line.map_err(|e| match e.kind() {
+            io::ErrorKind::InvalidData => WordListError::InvalidUtf8 { line: number },
+            _ => WordListError::Io(e),
+        });
+        (number, line)
+    })
+}
+
+/// Read one word per line
+fn read_lines(reader: impl Read) -> Result<Vec<String>, WordListError> {
+    let mut words = vec![];
+
+    for (i, line) in lines(reader) {
+        let line = line?;
+        if line.split_whitespace().count() > 1 {
+            return Err(WordListError::MultipleWords { line: i });
+        }
+        words.push(line);
+    }
+
+    Ok(words)
+}
+
+/// Read the words of one column of comma separated values
+fn read_csv(reader: impl Read, column: usize, header: bool) -> Result<Vec<String>, WordListError> {
+    let mut words = vec![];
+
+    for (i, line) in lines(reader).skip(usize::from(header)) {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mut fields = csv_fields(&line, i)?;
+        if column >= fields.len() {
+            return Err(WordListError::MissingColumn { line: i, column });
+        }
+        words.push(fields.swap_remove(column));
+    }
+
+    Ok(words)
+}
+
+/// Split a line of comma separated values into its fields
+/// Fields may be quoted, with `""` standing for a quote inside a quoted field
+fn csv_fields(line: &str, number: usize) -> Result<Vec<String>, WordListError> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(WordListError::UnterminatedQuote { line: number });
+    }
+    fields.push(field);
+
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_lines() -> Result<(), WordListError> {
+        let text = "  Hello \r\nÉCLAT\n\nhello\nwords\nhi\n";
+
+        let words: Vec<String> = Loader::new().load(text.as_bytes())?;
+        assert_eq!(words, vec!["Hello", "ÉCLAT", "hello", "words", "hi"]);
+
+        let loader = Loader::new()
+            .with_lowercase(true)
+            .with_strip_diacritics(true)
+            .with_dedup(true)
+            .with_length_range(3..=5);
+        let words: Vec<String> = loader.load(text.as_bytes())?;
+        assert_eq!(words, vec!["hello", "eclat", "words"]);
+
+        let words: Vec<String> = loader
+            .with_charset("abcdefghijklmnopqrstuvwxyz".chars().filter(|c| *c != 'c'))
+            .load(text.as_bytes())?;
+        assert_eq!(words, vec!["hello", "words"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_csv() -> Result<(), WordListError> {
+        let text = "id,word,count\n1,apple,3\n2,\"Berry\",4\n3,\"a,b\"\"c\",1\n";
+        let loader = Loader::new().with_format(Format::Csv {
+            column: 1,
+            header: true,
+        });
+
+        let words: Vec<String> = loader.load(text.as_bytes())?;
+        assert_eq!(words, vec!["apple", "Berry", "a,b\"c"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let csv = Loader::new().with_format(Format::Csv {
+            column: 1,
+            header: false,
+        });
+
+        let res = csv.load::<Vec<String>>("a,b\nc\n".as_bytes());
+        assert!(matches!(
+            res,
+            Err(WordListError::MissingColumn { line: 2, column: 1 })
+        ));
+
+        let res = csv.load::<Vec<String>>("a,\"b\n".as_bytes());
+        assert!(matches!(
+            res,
+            Err(WordListError::UnterminatedQuote { line: 1 })
+        ));
+
+        let res = Loader::new().load::<Vec<String>>("hello\nhello world\n".as_bytes());
+        assert!(matches!(res, Err(WordListError::MultipleWords { line: 2 })));
+
+        let res = Loader::new().load::<Vec<String>>(&b"hello\n\xff\xfe\n"[..]);
+        assert!(matches!(res, Err(WordListError::InvalidUtf8 { line: 2 })));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_load_json() -> Result<(), WordListError> {
+        let loader = Loader::new()
+            .with_format(Format::Json)
+            .with_lowercase(true)
+            .with_dedup(true);
+
+        let words: Vec<String> = loader.load(r#"["Hello", " world ", "HELLO"]"#.as_bytes())?;
+        assert_eq!(words, vec!["hello", "world"]);
+
+        let res = loader.load::<Vec<String>>(r#"{"words": []}"#.as_bytes());
+        assert!(matches!(res, Err(WordListError::Json(_))));
+
+        Ok(())
+    }
+}
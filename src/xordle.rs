@@ -0,0 +1,338 @@
+use std::fmt::Debug;
+
+use crate::dictionary::Dictionary;
+use crate::error::WordleError;
+use crate::guesser::{Guess, GuessResult, Guessable};
+use crate::rng::SplitMix64;
+use crate::{board_rows, check_guess, Playable};
+
+/// A game of Wordle with two correct words that share no atoms
+/// Every guess is scored against each correct word not found yet, and the
+/// two rows are merged per position into a single row of feedback
+///
+/// # Example
+///
+/// ```
+/// use rowdle::guesser::GuessResult;
+/// use rowdle::xordle::Xordle;
+///
+/// let word_list = vec!["fjord".to_string(), "bunch".to_string(), "forch".to_string()];
+/// let mut game = Xordle::new(9, ("fjord".to_string(), "bunch".to_string()), word_list).unwrap();
+///
+/// // every atom of forch is in one of the correct words
+/// let res = game.guess("forch".to_string()).unwrap();
+/// assert!(res.guess.iter().all(|r| matches!(r, GuessResult::Correct(_) | GuessResult::Misplaced(_))));
+///
+/// game.guess("fjord".to_string()).unwrap();
+/// game.guess("bunch".to_string()).unwrap();
+/// assert!(game.won());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xordle<
+    T: PartialEq + Clone + Debug,
+    G: Guessable<T> + Default + Debug,
+    D: Dictionary<G> = Vec<G>,
+> {
+    max_tries: u8,
+    correct_words: (G, G),
+    word_list: D,
+    answers: Option<Vec<G>>,
+    guesses: Vec<Guess<G, T>>,
+}
+
+impl<T: PartialEq + Clone + Debug, G: Guessable<T> + Default + Debug, D: Dictionary<G>>
+    Xordle<T, G, D>
+{
+    /// Create a new game
+    /// # Arguments
+    /// * `max_tries` - The maximum number of tries allowed
+    /// * `correct_words` - The two correct words, of the same length and without shared atoms
+    /// * `word_list` - A dictionary of words that can be guessed, also used as the list of possible answers
+    /// # Returns
+    /// The game, `WordLengthMismatch` with the second word if the lengths differ,
+    /// or `SharedAtoms` if the words share an atom
+    pub fn new(
+        max_tries: u8,
+        correct_words: (G, G),
+        word_list: D,
+    ) -> Result<Self, WordleError<G, T>> {
+        let (first, second) = &correct_words;
+        if first.atom_count() != second.atom_count() {
+            return Err(WordleError::WordLengthMismatch(second.clone()));
+        }
+        if !is_disjoint(first, second) {
+            return Err(WordleError::SharedAtoms(first.clone(), second.clone()));
+        }
+
+        Ok(Self {
+            max_tries,
+            correct_words,
+            word_list,
+            answers: None,
+            guesses: vec![],
+        })
+    }
+
+    /// Set the list of words that can be the correct words
+    /// Answers are always allowed as guesses
+    pub fn with_answers(mut self, answers: Vec<G>) -> Self {
+        self.answers = Some(answers);
+        self
+    }
+
+    /// Make a guess
+    /// # Arguments
+    /// * `word` - The word to guess
+    /// # Returns
+    /// The feedback of the correct words not found yet, merged per position
+    pub fn guess(&mut self, word: G) -> Result<Guess<G, T>, WordleError<G, T>> {
+        if self.won() {
+            return Err(WordleError::GameWon);
+        }
+        if self.game_over() {
+            return Err(WordleError::MaxTriesExceeded);
+        }
+
+        let word = check_guess(
+            word,
+            self.correct_words.0.atom_count(),
+            &self.word_list,
+            self.answers.as_deref(),
+            |w| self.is_word_guessed(w),
+        )?;
+
+        let (first, second) = &self.correct_words;
+        let res = match (self.is_word_guessed(first), self.is_word_guessed(second)) {
+            (false, false) => Guess {
+                guess: merge(&word.guess(first).guess, &word.guess(second).guess),
+                word,
+            },
+            (true, _) => word.guess(second),
+            (_, true) => word.guess(first),
+        };
+
+        self.guesses.push(res);
+        Ok(self.guesses.last().unwrap().clone())
+    }
+
+    /// Get the guesses made so far
+    pub fn guesses(&self) -> &[Guess<G, T>] {
+        &self.guesses
+    }
+
+    /// Check if a word has been guessed
+    pub fn is_word_guessed(&self, word: &G) -> bool {
+        self.guesses.iter().any(|g| g.word == *word)
+    }
+
+    /// Get the number of correct words found
+    pub fn found_count(&self) -> usize {
+        usize::from(self.is_word_guessed(&self.correct_words.0))
+            + usize::from(self.is_word_guessed(&self.correct_words.1))
+    }
+
+    /// Check if the game is won
+    /// A game is won if both correct words have been guessed
+    pub fn won(&self) -> bool {
+        self.found_count() == 2
+    }
+
+    /// Check if the game is lost
+    /// A game is lost if the maximum number of tries has been exceeded
+    pub fn lost(&self) -> bool {
+        self.guesses.len() >= self.max_tries as usize && !self.won()
+    }
+
+    /// Check if the game is over
+    /// A game is over if the game is won or lost
+    pub fn game_over(&self) -> bool {
+        self.won() || self.lost()
+    }
+
+    /// Get the correct words
+    pub fn correct_words(&self) -> (&G, &G) {
+        (&self.correct_words.0, &self.correct_words.1)
+    }
+
+    /// get a 2d vector of the board
+    /// # Arguments
+    /// * `pad` - The number of empty guesses to pad the board with
+    /// * `buffer` - A buffer guess to add to the board
+    /// # Returns
+    /// A 2d vector of the board
+    pub fn board(&self, pad: Option<u32>, buffer: Option<Guess<G, T>>) -> Vec<Guess<G, T>> {
+        board_rows(
+            &self.guesses,
+            self.correct_words.0.atom_count(),
+            self.max_tries,
+            pad,
+            buffer,
+        )
+    }
+}
+
+impl<T: PartialEq + Clone + Debug, G: Guessable<T> + Default + Debug, D: Dictionary<G>>
+    Playable<T, G> for Xordle<T, G, D>
+{
+    fn guess(&mut self, word: G) -> Result<Guess<G, T>, WordleError<G, T>> {
+        Xordle::guess(self, word)
+    }
+
+    fn won(&self) -> bool {
+        Xordle::won(self)
+    }
+
+    fn lost(&self) -> bool {
+        Xordle::lost(self)
+    }
+
+    fn board(&self, pad: Option<u32>, buffer: Option<Guess<G, T>>) -> Vec<Guess<G, T>> {
+        Xordle::board(self, pad, buffer)
+    }
+}
+
+/// Merge two rows of feedback for the same guess per position
+/// `Correct` wins over `Misplaced`, which wins over `Partial`, which wins over `Incorrect`
+/// # Arguments
+/// * `first` - The feedback against the first correct word
+/// * `second` - The feedback against the second correct word
+pub fn merge<T: PartialEq + Clone>(
+    first: &[GuessResult<T>],
+    second: &[GuessResult<T>],
+) -> Vec<GuessResult<T>> {
+    fn rank<T: PartialEq>(result: &GuessResult<T>) -> u8 {
+        match result {
+            GuessResult::Correct(_) => 4,
+            GuessResult::Misplaced(_) => 3,
+            GuessResult::Partial(_) => 2,
+            GuessResult::Incorrect(_)
+            | GuessResult::Custom(_)
+            | GuessResult::Higher(_)
+            | GuessResult::Lower(_) => 1,
+            GuessResult::Empty => 0,
+        }
+    }
+
+    first
+        .iter()
+        .zip(second)
+        .map(|(a, b)| if rank(b) > rank(a) { b } else { a }.clone())
+        .collect()
+}
+
+/// Check if two words share no atoms
+pub fn is_disjoint<T: PartialEq + Clone, G: Guessable<T>>(first: &G, second: &G) -> bool {
+    let atoms = first.atoms();
+    second.atoms().iter().all(|a| !atoms.contains(a))
+}
+
+/// Pick a pair of answers of the same length that share no atoms
+/// The pick is decided by a seeded SplitMix64 generator, so the same seed
+/// always picks the same pair
+/// # Arguments
+/// * `answers` - The words to pick from
+/// * `seed` - The seed of the pick
+/// # Returns
+/// The pair, or `None` if no two answers are disjoint
+pub fn pick_pair<T: PartialEq + Clone, G: Guessable<T>>(
+    answers: &[G],
+    seed: u64,
+) -> Option<(G, G)> {
+    let mut rng = SplitMix64::new(seed);
+    let mut order: Vec<usize> = (0..answers.len()).collect();
+    rng.shuffle(&mut order);
+
+    for i in order {
+        let first = &answers[i];
+        let partners: Vec<&G> = answers
+            .iter()
+            .filter(|w| w.atom_count() == first.atom_count() && is_disjoint(first, *w))
+            .collect();
+
+        if !partners.is_empty() {
+            let second = partners[rng.below(partners.len() as u64) as usize];
+            return Some((first.clone(), second.clone()));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::words;
+    use std::error::Error;
+
+    #[test]
+    fn test_xordle() -> Result<(), Box<dyn Error>> {
+        let word_list = words(&["fjord", "bunch", "hello", "crumb"]);
+        let mut game = Xordle::new(6, ("fjord".to_string(), "bunch".to_string()), word_list)?;
+
+        let res = game.guess("crumb".to_string())?;
+        assert_eq!(
+            res.guess,
+            vec![
+                GuessResult::Misplaced('c'),
+                GuessResult::Misplaced('r'),
+                GuessResult::Misplaced('u'),
+                GuessResult::Incorrect('m'),
+                GuessResult::Misplaced('b'),
+            ]
+        );
+
+        let res = game.guess("fjord".to_string())?;
+        assert!(res
+            .guess
+            .iter()
+            .all(|r| matches!(r, GuessResult::Correct(_))));
+        assert_eq!(game.found_count(), 1);
+        assert!(!game.won());
+
+        // fjord is found, so only bunch is scored
+        let res = game.guess("hello".to_string())?;
+        assert_eq!(res, "hello".to_string().guess(&"bunch".to_string()));
+
+        game.guess("bunch".to_string())?;
+        assert!(game.won());
+        assert_eq!(game.guess("crumb".to_string()), Err(WordleError::GameWon));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_xordle_targets() {
+        let word_list = words(&["fjord", "bunch", "hello", "hi"]);
+
+        let res = Xordle::new(
+            6,
+            ("fjord".to_string(), "hi".to_string()),
+            word_list.clone(),
+        );
+        assert_eq!(res, Err(WordleError::WordLengthMismatch("hi".to_string())));
+
+        let res = Xordle::new(6, ("fjord".to_string(), "hello".to_string()), word_list);
+        assert_eq!(
+            res,
+            Err(WordleError::SharedAtoms(
+                "fjord".to_string(),
+                "hello".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_pick_pair() {
+        let answers = words(&["hello", "jello", "fjord", "bunch", "crumb", "pygmy"]);
+
+        for seed in 0..50 {
+            let (first, second) = pick_pair(&answers, seed).unwrap();
+            assert!(is_disjoint(&first, &second));
+            assert_eq!(pick_pair(&answers, seed), Some((first, second)));
+        }
+
+        assert_eq!(pick_pair(&words(&["hello", "jello", "cello"]), 1), None);
+        assert_eq!(pick_pair::<char, String>(&[], 1), None);
+    }
+}